@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, PoeModuleConfig, Signature,
+	SudoConfig, SystemConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use hex_literal::hex;
@@ -60,6 +60,8 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Imported claims
+				vec![],
 				true,
 			)
 		},
@@ -107,6 +109,8 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Imported claims
+				vec![],
 				true,
 			)
 		},
@@ -150,6 +154,8 @@ pub fn dora_testnet_config() -> Result<ChainSpec, String> {
 					// $secret//two
 					hex!["d47753f0cca9dd8da00c70e82ec4fc5501a69c49a5952a643d18802837c88212"].into(),
 				],
+				// Imported claims
+				vec![],
 				true,
 			)
 		},
@@ -172,6 +178,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	initial_claims: Vec<(Vec<u8>, AccountId)>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -194,5 +201,9 @@ fn testnet_genesis(
 			// Assign network admin rights.
 			key: root_key,
 		},
+		poe_module: PoeModuleConfig {
+			// Claims carried over from a legacy notarisation system.
+			claims: initial_claims,
+		},
 	}
 }
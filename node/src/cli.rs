@@ -38,4 +38,7 @@ pub enum Subcommand {
 	/// The custom benchmark subcommmand benchmarking runtime pallets.
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Notarise files and verify their claims against the local database.
+	Poe(crate::poe_cmd::PoeSubcommand),
 }
@@ -18,6 +18,7 @@
 use crate::{
 	chain_spec,
 	cli::{Cli, Subcommand},
+	poe_cmd::PoeSubcommand,
 	service,
 };
 use node_template_runtime::Block;
@@ -126,6 +127,14 @@ pub fn run() -> sc_cli::Result<()> {
 				     `--features runtime-benchmarks`."
 					.into())
 			},
+		Some(Subcommand::Poe(PoeSubcommand::Hash(cmd))) => cmd.run(),
+		Some(Subcommand::Poe(PoeSubcommand::Verify(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, .. } = service::new_partial(&config)?;
+				Ok((cmd.run(client), task_manager))
+			})
+		},
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
 			runner.run_node_until_exit(|config| async move {
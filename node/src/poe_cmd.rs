@@ -0,0 +1,146 @@
+//! The `poe` subcommand, to notarise files and verify their claims against the local database.
+
+use std::{convert::TryInto, path::PathBuf, sync::Arc};
+
+use codec::Decode;
+use node_template_runtime::{
+	opaque::Block,
+	pallet_poe::{ClaimInfoOf, HashAlgorithm, ProofOf, Proofs},
+	Runtime,
+};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::ProofProvider;
+use sp_blockchain::HeaderBackend;
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::{BlakeTwo256, Header as HeaderT};
+use structopt::StructOpt;
+
+/// Proof of existence utilities.
+#[derive(Debug, StructOpt)]
+pub enum PoeSubcommand {
+	/// Compute the proof of a file, as claimed by `create_claim`.
+	Hash(HashCmd),
+
+	/// Look up the claim on a file at a given block of the local database.
+	Verify(VerifyCmd),
+}
+
+/// The file to notarise and how to hash it.
+#[derive(Debug, StructOpt)]
+pub struct ProofParams {
+	/// The file to notarise.
+	#[structopt(parse(from_os_str))]
+	pub file: PathBuf,
+
+	/// The hash function to compute the proof with: `blake2-256`, `sha2-256` or `keccak-256`.
+	#[structopt(long, default_value = "blake2-256", parse(try_from_str = parse_hash_algorithm))]
+	pub hasher: HashAlgorithm,
+}
+
+impl ProofParams {
+	/// The digest of the file, which is the proof bytes the pallet keys claims by.
+	pub fn proof(&self) -> sc_cli::Result<[u8; 32]> {
+		Ok(self.hasher.hash(&std::fs::read(&self.file)?))
+	}
+}
+
+fn parse_hash_algorithm(name: &str) -> Result<HashAlgorithm, String> {
+	match name {
+		"blake2-256" => Ok(HashAlgorithm::Blake2_256),
+		"sha2-256" => Ok(HashAlgorithm::Sha2_256),
+		"keccak-256" => Ok(HashAlgorithm::Keccak256),
+		_ => Err(format!("Unknown hash function `{}`", name)),
+	}
+}
+
+/// The `poe hash` command.
+#[derive(Debug, StructOpt)]
+pub struct HashCmd {
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub proof_params: ProofParams,
+}
+
+impl HashCmd {
+	/// Print the proof of the file as hex.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		println!("0x{}", HexDisplay::from(&self.proof_params.proof()?));
+		Ok(())
+	}
+}
+
+/// The `poe verify` command.
+#[derive(Debug, StructOpt)]
+pub struct VerifyCmd {
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub proof_params: ProofParams,
+
+	/// The block to look the claim up at, by hash or number.
+	#[structopt(long, value_name = "HASH or NUMBER")]
+	pub block: BlockNumberOrHash,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl VerifyCmd {
+	/// Read the claim on the file from a storage read proof at the given block, check the proof
+	/// against the state root of the block and print the owner of the claim.
+	pub async fn run<C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		C: ProofProvider<Block> + HeaderBackend<Block>,
+	{
+		let digest = self.proof_params.proof()?;
+		let proof: ProofOf<Runtime> =
+			digest.to_vec().try_into().map_err(|_| "The proof is longer than ProofMaxLength")?;
+		let key = Proofs::<Runtime>::hashed_key_for(&proof);
+
+		let id = self.block.parse::<Block>()?;
+		let header = client.header(id)?.ok_or("Unknown block")?;
+		let read_proof = client.read_proof(&id, &mut std::iter::once(key.as_slice()))?;
+		let mut values = sp_state_machine::read_proof_check::<BlakeTwo256, _>(
+			*header.state_root(),
+			read_proof,
+			std::iter::once(&key),
+		)
+		.map_err(|e| format!("Invalid storage read proof: {}", e))?;
+
+		let block_hash = header.hash();
+		println!("proof: 0x{}", HexDisplay::from(&digest));
+		println!("block: {:?} (#{})", block_hash, header.number());
+		match values.remove(&key).flatten() {
+			Some(value) => {
+				let claim = ClaimInfoOf::<Runtime>::decode(&mut &value[..])
+					.map_err(|e| format!("Invalid claim: {}", e))?;
+				println!("owner: {}", claim.owner);
+				println!("claimed at block: #{}", claim.created_at);
+				Ok(())
+			},
+			None => Err(format!("Not claimed at block {:?}", block_hash).into()),
+		}
+	}
+}
+
+impl CliConfiguration for VerifyCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
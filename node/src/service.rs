@@ -205,11 +205,16 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 
     let rpc_extensions_builder = {
         let client = client.clone();
+        let backend = backend.clone();
         let pool = transaction_pool.clone();
 
         Box::new(move |deny_unsafe, _| {
-            let deps =
-                crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                backend: backend.clone(),
+                pool: pool.clone(),
+                deny_unsafe,
+            };
 
             crate::rpc::create_full(deps)
         })
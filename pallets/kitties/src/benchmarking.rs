@@ -0,0 +1,121 @@
+//! Benchmarking setup for pallet-kitties
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as KittiesModule;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One, Zero};
+
+const SEED: u32 = 0;
+
+// Plenty of balance for `who`, with room left to receive the proceeds of a sale.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+// Create a kitty for `owner` and buy it, so `owner` can transfer or sell it.
+fn owned_kitty<T: Config>(owner: &T::AccountId) -> Result<T::KittyIndex, &'static str> {
+	fund::<T>(owner);
+	let kitty_id = KittiesCount::<T>::get().unwrap_or_else(Zero::zero);
+	KittiesModule::<T>::create(RawOrigin::Signed(owner.clone()).into())?;
+	KittiesModule::<T>::buy(RawOrigin::Signed(owner.clone()).into(), kitty_id)?;
+	Ok(kitty_id)
+}
+
+benchmarks! {
+	create {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Creator::<T>::get(T::KittyIndex::zero()), Some(caller));
+	}
+
+	transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = owned_kitty::<T>(&caller)?;
+		let receiver: T::AccountId = account("receiver", 0, SEED);
+	}: _(RawOrigin::Signed(caller), receiver.clone(), kitty_id)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(receiver));
+	}
+
+	breed {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		KittiesModule::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+		KittiesModule::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+		let kitty_id_1 = T::KittyIndex::zero();
+		let kitty_id_2 = T::KittyIndex::one();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id_1, kitty_id_2)
+	verify {
+		assert_eq!(Owner::<T>::get(T::KittyIndex::from(2u32)), Some(caller));
+	}
+
+	buy {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		KittiesModule::<T>::create(RawOrigin::Signed(caller.clone()).into())?;
+		let kitty_id = T::KittyIndex::zero();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(caller));
+	}
+
+	sell {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = owned_kitty::<T>(&caller)?;
+		fund::<T>(&KittiesModule::<T>::account_id());
+		let price = T::Currency::minimum_balance();
+	}: _(RawOrigin::Signed(caller), kitty_id, price)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(KittiesModule::<T>::account_id()));
+	}
+
+	list {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = owned_kitty::<T>(&caller)?;
+		let price = T::Currency::minimum_balance();
+	}: _(RawOrigin::Signed(caller), kitty_id, price)
+	verify {
+		assert_eq!(Listings::<T>::get(kitty_id), Some(price));
+	}
+
+	update_price {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = owned_kitty::<T>(&caller)?;
+		let price = T::Currency::minimum_balance();
+		KittiesModule::<T>::list(RawOrigin::Signed(caller.clone()).into(), kitty_id, price)?;
+		let new_price = price * 2u32.into();
+	}: _(RawOrigin::Signed(caller), kitty_id, new_price)
+	verify {
+		assert_eq!(Listings::<T>::get(kitty_id), Some(new_price));
+	}
+
+	unlist {
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = owned_kitty::<T>(&caller)?;
+		KittiesModule::<T>::list(RawOrigin::Signed(caller.clone()).into(), kitty_id, T::Currency::minimum_balance())?;
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(!Listings::<T>::contains_key(kitty_id));
+	}
+
+	buy_listed {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let kitty_id = owned_kitty::<T>(&seller)?;
+		let price = T::Currency::minimum_balance();
+		KittiesModule::<T>::list(RawOrigin::Signed(seller).into(), kitty_id, price)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_eq!(Owner::<T>::get(kitty_id), Some(caller));
+		assert!(!Listings::<T>::contains_key(kitty_id));
+	}
+}
+
+impl_benchmark_test_suite!(KittiesModule, crate::mock::new_test_ext(), crate::mock::Test);
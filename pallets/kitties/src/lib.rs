@@ -8,15 +8,20 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
         dispatch::DispatchResult, PalletId, pallet_prelude::*, 
-        traits::{Randomness, Currency, ReservableCurrency, BalanceStatus, ExistenceRequirement::{AllowDeath}}
+        traits::{Randomness, Currency, ReservableCurrency, BalanceStatus, ExistenceRequirement::{AllowDeath, KeepAlive}}
     };
 	use frame_system::pallet_prelude::*;
 	use codec::{Encode, Decode};
+	use crate::weights::WeightInfo;
 
 	use sp_io::hashing::blake2_128;
     use sp_runtime::{
@@ -24,7 +29,7 @@ pub mod pallet {
             Zero, AtLeast32BitUnsigned, MaybeSerializeDeserialize, Bounded, One, AccountIdConversion
         },
     };
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[derive(Encode, Decode)]
 	pub struct Kitty(pub [u8;16]);
@@ -40,6 +45,8 @@ pub mod pallet {
         type BalanceToReserve: Get<BalanceOf<Self>>;
         #[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -54,6 +61,14 @@ pub mod pallet {
 		KittyTransfer(T::AccountId, T::AccountId, T::KittyIndex),
         KittyBuy(T::AccountId, T::KittyIndex, BalanceOf<T>),
         KittySell(T::AccountId, T::KittyIndex, BalanceOf<T>),
+		/// A kitty was listed for sale by its owner at a price. \[owner, kitty_id, price\]
+		KittyListed(T::AccountId, T::KittyIndex, BalanceOf<T>),
+		/// The price of a listed kitty changed. \[owner, kitty_id, price\]
+		KittyPriceUpdated(T::AccountId, T::KittyIndex, BalanceOf<T>),
+		/// A kitty was taken off the market. \[owner, kitty_id\]
+		KittyUnlisted(T::AccountId, T::KittyIndex),
+		/// A listed kitty was bought. \[buyer, seller, kitty_id, price\]
+		ListedKittyBought(T::AccountId, T::AccountId, T::KittyIndex, BalanceOf<T>),
 	}
 
 	#[pallet::storage]
@@ -72,6 +87,11 @@ pub mod pallet {
 	#[pallet::getter(fn creator)]
 	pub type Creator<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, Option<T::AccountId>, ValueQuery>;
 
+	/// The asking price of kitties listed for sale by their owner.
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, BalanceOf<T>>;
+
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -83,11 +103,19 @@ pub mod pallet {
         InsufficientBalanceToReserve,
         RepatriateFailed,
         PriceTooHigh,
+		/// The kitty is already listed, use `update_price` to change its price
+		AlreadyListed,
+		/// The kitty is not listed for sale
+		NotListed,
+		/// The asking price is above the most the buyer is willing to pay
+		PriceAboveMax,
+		/// The owner of a kitty can not buy it
+		BuyerIsOwner,
 	}
 
 	#[pallet::call]
 	impl<T:Config> Pallet<T> {
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::create())]
 		pub fn create(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
             let kitty_id = match Self::kitties_count() {
@@ -110,20 +138,21 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(origin: OriginFor<T>, new_owner: T::AccountId, kitty_id: T::KittyIndex) ->
 			DispatchResult
 		{
 			let who = ensure_signed(origin)?;
 			ensure!(Some(who.clone()) == Owner::<T>::get(kitty_id), Error::<T>::NotOwner);
 			Owner::<T>::insert(kitty_id, Some(new_owner.clone()));
+			Listings::<T>::remove(kitty_id);
 
 			Self::deposit_event(Event::KittyTransfer(who, new_owner, kitty_id));
 
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::breed())]
 		pub fn breed(origin: OriginFor<T>, kitty_id_1: T::KittyIndex, kitty_id_2: T::KittyIndex)
 			-> DispatchResult
 		{
@@ -156,7 +185,7 @@ pub mod pallet {
 
 			Ok(())
 		}
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::buy())]
 		pub fn buy(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(Some(who.clone()) == Creator::<T>::get(kitty_id), Error::<T>::NotCreator);
@@ -174,7 +203,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(0)]
+        #[pallet::weight(T::WeightInfo::sell())]
 		pub fn sell(origin: OriginFor<T>, kitty_id: T::KittyIndex, #[pallet::compact] price: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(Some(who.clone()) == Owner::<T>::get(kitty_id), Error::<T>::NotOwner);
@@ -184,10 +213,69 @@ pub mod pallet {
 
             let _ = T::Currency::transfer(&Self::account_id(), &who, price, AllowDeath);
             Owner::<T>::insert(kitty_id, Some(Self::account_id()));
+            Listings::<T>::remove(kitty_id);
             Self::deposit_event(Event::KittySell(who, kitty_id, price));
 
             Ok(())
         }
+
+		/// List a kitty of the caller for sale at `price`, to be bought by any account with
+		/// `buy_listed`.
+		#[pallet::weight(T::WeightInfo::list())]
+		pub fn list(origin: OriginFor<T>, kitty_id: T::KittyIndex, #[pallet::compact] price: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Some(who.clone()) == Owner::<T>::get(kitty_id), Error::<T>::NotOwner);
+			ensure!(!Listings::<T>::contains_key(kitty_id), Error::<T>::AlreadyListed);
+			Listings::<T>::insert(kitty_id, price);
+
+			Self::deposit_event(Event::KittyListed(who, kitty_id, price));
+
+			Ok(())
+		}
+
+		/// Change the price of a kitty the caller listed.
+		#[pallet::weight(T::WeightInfo::update_price())]
+		pub fn update_price(origin: OriginFor<T>, kitty_id: T::KittyIndex, #[pallet::compact] price: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Some(who.clone()) == Owner::<T>::get(kitty_id), Error::<T>::NotOwner);
+			ensure!(Listings::<T>::contains_key(kitty_id), Error::<T>::NotListed);
+			Listings::<T>::insert(kitty_id, price);
+
+			Self::deposit_event(Event::KittyPriceUpdated(who, kitty_id, price));
+
+			Ok(())
+		}
+
+		/// Take a kitty of the caller off the market.
+		#[pallet::weight(T::WeightInfo::unlist())]
+		pub fn unlist(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Some(who.clone()) == Owner::<T>::get(kitty_id), Error::<T>::NotOwner);
+			ensure!(Listings::<T>::take(kitty_id).is_some(), Error::<T>::NotListed);
+
+			Self::deposit_event(Event::KittyUnlisted(who, kitty_id));
+
+			Ok(())
+		}
+
+		/// Buy a listed kitty, paying its owner the asking price as long as it is no more than
+		/// `max_price`.
+		#[pallet::weight(T::WeightInfo::buy_listed())]
+		pub fn buy_listed(origin: OriginFor<T>, kitty_id: T::KittyIndex, #[pallet::compact] max_price: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let price = Listings::<T>::get(kitty_id).ok_or(Error::<T>::NotListed)?;
+			ensure!(price <= max_price, Error::<T>::PriceAboveMax);
+			let seller = Owner::<T>::get(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+			ensure!(who != seller, Error::<T>::BuyerIsOwner);
+
+			T::Currency::transfer(&who, &seller, price, KeepAlive)?;
+			Owner::<T>::insert(kitty_id, Some(who.clone()));
+			Listings::<T>::remove(kitty_id);
+
+			Self::deposit_event(Event::ListedKittyBought(who, seller, kitty_id, price));
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
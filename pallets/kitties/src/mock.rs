@@ -84,6 +84,7 @@ impl pallet_kitties::Config for Test {
     type Randomness = TestRandomness<Self>;
     type BalanceToReserve = BalanceToReserve;
     type PalletId = KittiesPalletId;
+    type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -245,3 +245,159 @@ fn breed_kitty_should_fail() {
 		);
     })
 }
+
+#[test]
+fn list_kitty_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let sender = 1;
+        assert_ok!(KittiesModule::create(Origin::signed(sender)));
+        // activate the system's account
+        let _ = Balances::transfer(Origin::signed(4), KittiesModule::account_id(), 1);
+        assert_ok!(KittiesModule::buy(Origin::signed(sender), 0));
+
+        assert_ok!(KittiesModule::list(Origin::signed(sender), 0, 30));
+        assert_eq!(Listings::<Test>::get(0), Some(30));
+        assert_eq!(
+			last_event(),
+			Event::KittiesModule(crate::Event::KittyListed(sender, 0, 30))
+		);
+
+        assert_ok!(KittiesModule::update_price(Origin::signed(sender), 0, 40));
+        assert_eq!(Listings::<Test>::get(0), Some(40));
+        assert_eq!(
+			last_event(),
+			Event::KittiesModule(crate::Event::KittyPriceUpdated(sender, 0, 40))
+		);
+
+        assert_ok!(KittiesModule::unlist(Origin::signed(sender), 0));
+        assert_eq!(Listings::<Test>::get(0), None);
+        assert_eq!(
+			last_event(),
+			Event::KittiesModule(crate::Event::KittyUnlisted(sender, 0))
+		);
+    })
+}
+
+#[test]
+fn list_kitty_should_fail() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let sender = 1;
+        let hacker = 2;
+        assert_ok!(KittiesModule::create(Origin::signed(sender)));
+        // activate the system's account
+        let _ = Balances::transfer(Origin::signed(4), KittiesModule::account_id(), 1);
+        assert_ok!(KittiesModule::buy(Origin::signed(sender), 0));
+
+        // only the owner can list, and only once
+        assert_noop!(
+            KittiesModule::list(Origin::signed(hacker), 0, 30),
+			Error::<Test>::NotOwner
+		);
+        assert_noop!(
+            KittiesModule::update_price(Origin::signed(sender), 0, 30),
+			Error::<Test>::NotListed
+		);
+        assert_noop!(
+            KittiesModule::unlist(Origin::signed(sender), 0),
+			Error::<Test>::NotListed
+		);
+        assert_ok!(KittiesModule::list(Origin::signed(sender), 0, 30));
+        assert_noop!(
+            KittiesModule::list(Origin::signed(sender), 0, 40),
+			Error::<Test>::AlreadyListed
+		);
+        assert_noop!(
+            KittiesModule::update_price(Origin::signed(hacker), 0, 40),
+			Error::<Test>::NotOwner
+		);
+        assert_noop!(
+            KittiesModule::unlist(Origin::signed(hacker), 0),
+			Error::<Test>::NotOwner
+		);
+    })
+}
+
+#[test]
+fn buy_listed_kitty_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let seller = 1;
+        let buyer = 2;
+        assert_ok!(KittiesModule::create(Origin::signed(seller)));
+        // activate the system's account
+        let _ = Balances::transfer(Origin::signed(4), KittiesModule::account_id(), 1);
+        assert_ok!(KittiesModule::buy(Origin::signed(seller), 0));
+        assert_ok!(KittiesModule::list(Origin::signed(seller), 0, 30));
+
+        // the buyer pays the seller directly
+        assert_ok!(KittiesModule::buy_listed(Origin::signed(buyer), 0, 35));
+        assert_eq!(Balances::free_balance(seller), 110);
+        assert_eq!(Balances::free_balance(buyer), 70);
+        assert_eq!(
+			Owner::<Test>::get(0),
+            Some(buyer)
+		);
+        assert_eq!(Listings::<Test>::get(0), None);
+        assert_eq!(
+			last_event(),
+			Event::KittiesModule(crate::Event::ListedKittyBought(buyer, seller, 0, 30))
+		);
+    })
+}
+
+#[test]
+fn buy_listed_kitty_should_fail() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let seller = 1;
+        let buyer = 2;
+        let poor = 9;
+        assert_ok!(KittiesModule::create(Origin::signed(seller)));
+        // activate the system's account
+        let _ = Balances::transfer(Origin::signed(4), KittiesModule::account_id(), 1);
+        assert_ok!(KittiesModule::buy(Origin::signed(seller), 0));
+        assert_noop!(
+            KittiesModule::buy_listed(Origin::signed(buyer), 0, 30),
+			Error::<Test>::NotListed
+		);
+
+        assert_ok!(KittiesModule::list(Origin::signed(seller), 0, 30));
+        assert_noop!(
+            KittiesModule::buy_listed(Origin::signed(buyer), 0, 29),
+			Error::<Test>::PriceAboveMax
+		);
+        assert_noop!(
+            KittiesModule::buy_listed(Origin::signed(seller), 0, 30),
+			Error::<Test>::BuyerIsOwner
+		);
+        assert_noop!(
+            KittiesModule::buy_listed(Origin::signed(poor), 0, 30),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+    })
+}
+
+#[test]
+fn transfer_kitty_should_clear_listing() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let sender = 1;
+        let beneficiary = 2;
+        let buyer = 3;
+        assert_ok!(KittiesModule::create(Origin::signed(sender)));
+        // activate the system's account
+        let _ = Balances::transfer(Origin::signed(4), KittiesModule::account_id(), 1);
+        assert_ok!(KittiesModule::buy(Origin::signed(sender), 0));
+        assert_ok!(KittiesModule::list(Origin::signed(sender), 0, 30));
+
+        // the listing of the previous owner can not be bought any more
+        assert_ok!(KittiesModule::transfer(Origin::signed(sender), beneficiary, 0));
+        assert_eq!(Listings::<Test>::get(0), None);
+        assert_noop!(
+            KittiesModule::buy_listed(Origin::signed(buyer), 0, 30),
+			Error::<Test>::NotListed
+		);
+    })
+}
@@ -0,0 +1,189 @@
+//! Weights for pallet_kitties
+//!
+//! ESTIMATES ONLY: these numbers were written by hand from the storage accesses of every call
+//! and have not been produced by a benchmark run. Regenerate this file from the benchmarks in
+//! `benchmarking.rs` before relying on it for fees:
+//!
+//! ```text
+//! ./target/release/node-template benchmark --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet-kitties --extrinsic=* --execution=wasm --wasm-execution=compiled \
+//!     --heap-pages=4096 --output=./pallets/kitties/src/weights.rs \
+//!     --template=./.maintain/frame-template-weight.hbs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_kitties.
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn transfer() -> Weight;
+	fn breed() -> Weight;
+	fn buy() -> Weight;
+	fn sell() -> Weight;
+	fn list() -> Weight;
+	fn update_price() -> Weight;
+	fn unlist() -> Weight;
+	fn buy_listed() -> Weight;
+}
+
+/// Estimated weights for pallet_kitties on the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Kitties KittiesCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: System ExtrinsicIndex (r:1 w:0)
+	// Storage: Kitties Kitties (r:0 w:1)
+	// Storage: Kitties Owner (r:0 w:1)
+	// Storage: Kitties Creator (r:0 w:1)
+	fn create() -> Weight {
+		(41_306_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:1)
+	// Storage: Kitties Listings (r:0 w:1)
+	fn transfer() -> Weight {
+		(17_852_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Kitties Kitties (r:2 w:1)
+	// Storage: Kitties KittiesCount (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: System ExtrinsicIndex (r:1 w:0)
+	// Storage: Kitties Owner (r:0 w:1)
+	fn breed() -> Weight {
+		(30_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Kitties Creator (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Kitties Owner (r:0 w:1)
+	fn buy() -> Weight {
+		(44_925_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Kitties Listings (r:0 w:1)
+	fn sell() -> Weight {
+		(49_370_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:0)
+	// Storage: Kitties Listings (r:1 w:1)
+	fn list() -> Weight {
+		(21_604_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:0)
+	// Storage: Kitties Listings (r:1 w:1)
+	fn update_price() -> Weight {
+		(21_377_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:0)
+	// Storage: Kitties Listings (r:1 w:1)
+	fn unlist() -> Weight {
+		(20_839_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Kitties Listings (r:1 w:1)
+	// Storage: Kitties Owner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn buy_listed() -> Weight {
+		(52_146_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Kitties KittiesCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: System ExtrinsicIndex (r:1 w:0)
+	// Storage: Kitties Kitties (r:0 w:1)
+	// Storage: Kitties Owner (r:0 w:1)
+	// Storage: Kitties Creator (r:0 w:1)
+	fn create() -> Weight {
+		(41_306_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:1)
+	// Storage: Kitties Listings (r:0 w:1)
+	fn transfer() -> Weight {
+		(17_852_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Kitties Kitties (r:2 w:1)
+	// Storage: Kitties KittiesCount (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: System ExtrinsicIndex (r:1 w:0)
+	// Storage: Kitties Owner (r:0 w:1)
+	fn breed() -> Weight {
+		(30_118_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Kitties Creator (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Kitties Owner (r:0 w:1)
+	fn buy() -> Weight {
+		(44_925_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Kitties Listings (r:0 w:1)
+	fn sell() -> Weight {
+		(49_370_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:0)
+	// Storage: Kitties Listings (r:1 w:1)
+	fn list() -> Weight {
+		(21_604_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:0)
+	// Storage: Kitties Listings (r:1 w:1)
+	fn update_price() -> Weight {
+		(21_377_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Kitties Owner (r:1 w:0)
+	// Storage: Kitties Listings (r:1 w:1)
+	fn unlist() -> Weight {
+		(20_839_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Kitties Listings (r:1 w:1)
+	// Storage: Kitties Owner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn buy_listed() -> Weight {
+		(52_146_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+}
@@ -0,0 +1,33 @@
+//! Runtime API definition for the proof of existence pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_poe::{content_key, ClaimInfo, ClaimStatus, RegistryId};
+
+sp_api::decl_runtime_apis! {
+	pub trait PoeApi<AccountId, BlockNumber, Balance> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// The claim of `proof`, if any.
+		fn claim(proof: Vec<u8>) -> Option<ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>>;
+		/// The claim of `proof` in a registry, if any.
+		fn registry_claim(
+			registry_id: RegistryId,
+			proof: Vec<u8>,
+		) -> Option<ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>>;
+		/// Up to `limit` proofs claimed by `account`, skipping the first `start` of them. The
+		/// limit is capped at `MaxClaimsPerAccount`.
+		fn claims_of(account: AccountId, start: u32, limit: u32) -> Vec<Vec<u8>>;
+		/// Whether `proof` is currently claimed.
+		fn is_claimed(proof: Vec<u8>) -> bool;
+		/// Whether `proof` is claimed, revoked or has never been claimed.
+		fn claim_status(proof: Vec<u8>) -> ClaimStatus<AccountId, BlockNumber, Balance>;
+		/// Whether `leaf` is included in the anchored Merkle `root` following `proof_path`.
+		fn verify_inclusion(root: [u8; 32], leaf: [u8; 32], proof_path: Vec<[u8; 32]>) -> bool;
+	}
+}
@@ -0,0 +1,217 @@
+//! RPC interface for the proof of existence pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+pub use pallet_poe_rpc_runtime_api::PoeApi as PoeRuntimeApi;
+use pallet_poe_rpc_runtime_api::{content_key, ClaimInfo, ClaimStatus, RegistryId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	offchain::{OffchainStorage, STORAGE_PREFIX},
+	Bytes, H256,
+};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber, Balance> {
+	/// The claim of `proof`, if any.
+	#[rpc(name = "poe_claim")]
+	fn claim(
+		&self,
+		proof: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<Option<ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>>>;
+
+	/// The claim of `proof` in a registry, if any.
+	#[rpc(name = "poe_registryClaim")]
+	fn registry_claim(
+		&self,
+		registry_id: RegistryId,
+		proof: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<Option<ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>>>;
+
+	/// Up to `limit` proofs claimed by `account`, skipping the first `start` of them. The
+	/// limit is capped at `MaxClaimsPerAccount`.
+	#[rpc(name = "poe_claimsOf")]
+	fn claims_of(
+		&self,
+		account: AccountId,
+		start: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<Bytes>>;
+
+	/// Whether `proof` is currently claimed.
+	#[rpc(name = "poe_isClaimed")]
+	fn is_claimed(&self, proof: Bytes, at: Option<BlockHash>) -> Result<bool>;
+
+	/// Whether `proof` is claimed, revoked or has never been claimed.
+	#[rpc(name = "poe_claimStatus")]
+	fn claim_status(
+		&self,
+		proof: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<ClaimStatus<AccountId, BlockNumber, Balance>>;
+
+	/// Whether `leaf` is included in the anchored Merkle `root` following `proof_path`.
+	#[rpc(name = "poe_verifyInclusion")]
+	fn verify_inclusion(
+		&self,
+		root: H256,
+		leaf: H256,
+		proof_path: Vec<H256>,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+}
+
+/// Content of claims, served from the offchain database of the node.
+#[rpc]
+pub trait PoeContentApi {
+	/// The content claimed under the Blake2-256 `hash` by `create_claim_with_content`, if the
+	/// node indexed it. Requires the node to run with `--enable-offchain-indexing true`.
+	#[rpc(name = "poe_content")]
+	fn content(&self, hash: H256) -> Result<Option<Bytes>>;
+}
+
+/// A struct that implements the [`PoeApi`].
+pub struct Poe<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Poe<C, P> {
+	/// Create new `Poe` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to query claims.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, AccountId, BlockNumber, Balance>
+	PoeApi<<Block as BlockT>::Hash, AccountId, BlockNumber, Balance> for Poe<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, Balance>,
+	AccountId: Codec,
+	BlockNumber: Codec,
+	Balance: Codec,
+{
+	fn claim(
+		&self,
+		proof: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.claim(&at, proof.to_vec()).map_err(runtime_error)
+	}
+
+	fn registry_claim(
+		&self,
+		registry_id: RegistryId,
+		proof: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.registry_claim(&at, registry_id, proof.to_vec()).map_err(runtime_error)
+	}
+
+	fn claims_of(
+		&self,
+		account: AccountId,
+		start: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<Bytes>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.claims_of(&at, account, start, limit)
+			.map(|proofs| proofs.into_iter().map(Bytes).collect())
+			.map_err(runtime_error)
+	}
+
+	fn is_claimed(&self, proof: Bytes, at: Option<<Block as BlockT>::Hash>) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.is_claimed(&at, proof.to_vec()).map_err(runtime_error)
+	}
+
+	fn claim_status(
+		&self,
+		proof: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<ClaimStatus<AccountId, BlockNumber, Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.claim_status(&at, proof.to_vec()).map_err(runtime_error)
+	}
+
+	fn verify_inclusion(
+		&self,
+		root: H256,
+		leaf: H256,
+		proof_path: Vec<H256>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let proof_path = proof_path.into_iter().map(|node| node.to_fixed_bytes()).collect();
+
+		api.verify_inclusion(&at, root.to_fixed_bytes(), leaf.to_fixed_bytes(), proof_path)
+			.map_err(runtime_error)
+	}
+}
+
+/// A struct that implements the [`PoeContentApi`].
+pub struct PoeContent<S> {
+	storage: S,
+}
+
+impl<S> PoeContent<S> {
+	/// Create new `PoeContent` reading from the given offchain storage.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S> PoeContentApi for PoeContent<S>
+where
+	S: 'static + OffchainStorage,
+{
+	fn content(&self, hash: H256) -> Result<Option<Bytes>> {
+		let key = content_key(hash.as_bytes());
+		Ok(self.storage.get(STORAGE_PREFIX, &key).map(Bytes))
+	}
+}
@@ -1,25 +1,433 @@
 //! Benchmarking setup for pallet-poe
-// 本示例用于展示第6课的作业，参照作业要求，本示例使用了第一节课的 poe module 来测试 benchmark.
-// 由于时间所限，暂时只选取了1个方法，即 create_claim。运行图片位于项目的 docs 目录，json 文件位于根目录
-// TODO: 研究 extrinsics 参数如何支持通配符，参考 parity 官方的 tips module 优化 benchmark 的测试用例
 
 use super::*;
 
 #[allow(unused)]
 use crate::Pallet as POE;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, EnsureOrigin, Hooks, UnfilteredDispatchable};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::convert::TryInto;
+
+const SEED: u32 = 0;
+
+// Claim a proof of `len` bytes for `owner`, who can afford any deposit.
+fn create_claim_of<T: Config>(owner: &T::AccountId, len: u32) -> Result<Vec<u8>, &'static str> {
+	T::Currency::make_free_balance_be(owner, BalanceOf::<T>::max_value());
+	let proof = vec![1u8; len as usize];
+	POE::<T>::do_create_claim(DEFAULT_REGISTRY, owner.clone(), bounded::<T>(&proof), Some(HashAlgorithm::Blake2_256), None)?;
+	Ok(proof)
+}
+
+// The stored form of `proof`, which must be within the proof length bounds.
+fn bounded<T: Config>(proof: &[u8]) -> ProofOf<T> {
+	proof.to_vec().try_into().expect("proof is at most ProofMaxLength bytes")
+}
+
+// Claim `MaxClaimsPerAccount - 1` other proofs for `owner`, who can afford any deposit, so one
+// more claim fills up its index.
+fn create_other_claims_of<T: Config>(owner: &T::AccountId) -> Result<(), &'static str> {
+	T::Currency::make_free_balance_be(owner, BalanceOf::<T>::max_value());
+	for i in 1 .. T::MaxClaimsPerAccount::get() {
+		let digest = HashAlgorithm::Blake2_256.hash(&(owner, i).encode());
+		POE::<T>::do_create_claim(DEFAULT_REGISTRY, owner.clone(), bounded::<T>(&digest), Some(HashAlgorithm::Blake2_256), None)?;
+	}
+	Ok(())
+}
+
+// Fill the provenance log of `proof`, so the next entry drops the oldest one.
+fn fill_history<T: Config>(proof: &[u8], owner: &T::AccountId) {
+	let now = frame_system::Pallet::<T>::block_number();
+	for _ in 0 .. T::MaxHistoryLength::get() {
+		POE::<T>::record_provenance(&bounded::<T>(proof), owner.clone(), now, ClaimAction::Created);
+	}
+}
+
+// Share the claim of `proof` by `owner` with `MaxCoOwners - 1` co-owners, all needed for an
+// action, and return them.
+fn set_co_owners_of<T: Config>(owner: &T::AccountId, proof: &[u8]) -> Result<Vec<T::AccountId>, &'static str> {
+	let c = T::MaxCoOwners::get();
+	let co_owners: Vec<T::AccountId> = (1 .. c).map(|i| account("co_owner", i, SEED)).collect();
+	POE::<T>::set_co_owners(RawOrigin::Signed(owner.clone()).into(), proof.to_vec(), co_owners.clone(), c)?;
+	Ok(co_owners)
+}
+
+// An account that exists, so it can receive a claim together with its deposit.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, T::Currency::minimum_balance());
+	who
+}
+
+// A members-only policy admitting the longest proofs.
+fn registry_policy<T: Config>() -> RegistryPolicy {
+	RegistryPolicy { claimers: ClaimPolicy::Members, max_proof_length: T::ProofMaxLength::get() }
+}
+
+// Create a members-only registry administered by `admin`, who can afford any deposit.
+fn create_registry_of<T: Config>(admin: &T::AccountId) -> Result<RegistryId, &'static str> {
+	T::Currency::make_free_balance_be(admin, BalanceOf::<T>::max_value());
+	POE::<T>::create_registry(RawOrigin::Signed(admin.clone()).into(), registry_policy::<T>())?;
+	Ok(LastRegistryId::<T>::get())
+}
 
 benchmarks! {
 	create_claim {
-		let s in 0 .. 100;
-		let claim = b"claim".to_vec();
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
 		let caller: T::AccountId = whitelisted_caller();
-		//let current_block = <frame_system::Pallet<T>>::block_number();
-	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller.clone()), digest, HashAlgorithm::Blake2_256, None)
 	verify {
 		let current_block = <frame_system::Pallet<T>>::block_number();
-		assert_eq!(Proofs::<T>::get(claim), (caller, current_block));
+		let info = Proofs::<T>::get(bounded::<T>(&digest)).unwrap();
+		assert_eq!((info.owner, info.block_number), (caller, current_block));
+	}
+
+	create_claim_with_content {
+		let c in 0 .. T::MaxContentLength::get();
+		let content = vec![1u8; c as usize];
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller.clone()), content.clone())
+	verify {
+		let digest = HashAlgorithm::Blake2_256.hash(&content);
+		assert_eq!(Proofs::<T>::get(bounded::<T>(&digest)).unwrap().owner, caller);
+	}
+
+	on_initialize {
+		let e in 0 .. T::MaxExpiriesPerBlock::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let expiry: T::BlockNumber = 2u32.into();
+		for i in 0 .. e {
+			let digest = HashAlgorithm::Blake2_256.hash(&i.to_le_bytes());
+			POE::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), digest, HashAlgorithm::Blake2_256, Some(expiry))?;
+		}
+	}: { POE::<T>::on_initialize(expiry); }
+	verify {
+		assert!(!ClaimExpiries::<T>::contains_key(expiry));
+	}
+
+	revoke_claim {
+		let s in T::ProofMinLength::get() .. T::ProofMaxLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		create_other_claims_of::<T>(&caller)?;
+		let proof = vec![1u8; s as usize];
+		// a claim queued for expiry in a full block, which revoking takes out of the queue
+		let expiry: T::BlockNumber = 10u32.into();
+		for i in 1 .. T::MaxExpiriesPerBlock::get() {
+			let digest = HashAlgorithm::Blake2_256.hash(&i.to_le_bytes());
+			ClaimExpiries::<T>::try_append(expiry, bounded::<T>(&digest)).map_err(|_| "expiries are full")?;
+		}
+		POE::<T>::do_create_claim(DEFAULT_REGISTRY, caller.clone(), bounded::<T>(&proof), Some(HashAlgorithm::Blake2_256), Some(expiry))?;
+		fill_history::<T>(&proof, &caller);
+		// every other co-owner approved already
+		for co_owner in set_co_owners_of::<T>(&caller, &proof)? {
+			POE::<T>::revoke_claim(RawOrigin::Signed(co_owner).into(), proof.clone())?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone())
+	verify {
+		assert!(!Proofs::<T>::contains_key(&bounded::<T>(&proof)));
+		assert_eq!(ClaimExpiries::<T>::decode_len(expiry), Some(T::MaxExpiriesPerBlock::get() as usize - 1));
+	}
+
+	transfer_claim {
+		let s in T::ProofMinLength::get() .. T::ProofMaxLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		create_other_claims_of::<T>(&caller)?;
+		let proof = create_claim_of::<T>(&caller, s)?;
+		fill_history::<T>(&proof, &caller);
+		let receiver = funded_account::<T>("receiver", 0);
+		create_other_claims_of::<T>(&receiver)?;
+		// every other co-owner approved already
+		for co_owner in set_co_owners_of::<T>(&caller, &proof)? {
+			POE::<T>::transfer_claim(RawOrigin::Signed(co_owner).into(), proof.clone(), receiver.clone())?;
+		}
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone(), receiver.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&bounded::<T>(&proof)).unwrap().owner, receiver);
+	}
+
+	offer_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = create_claim_of::<T>(&caller, T::ProofMaxLength::get())?;
+		let receiver: T::AccountId = account("receiver", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone(), receiver)
+	verify {
+		assert!(PendingTransfers::<T>::contains_key(&bounded::<T>(&proof)));
+	}
+
+	accept_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = create_claim_of::<T>(&caller, T::ProofMaxLength::get())?;
+		let receiver = funded_account::<T>("receiver", 0);
+		POE::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), receiver.clone())?;
+	}: _(RawOrigin::Signed(receiver.clone()), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&bounded::<T>(&proof)).unwrap().owner, receiver);
+	}
+
+	cancel_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = create_claim_of::<T>(&caller, T::ProofMaxLength::get())?;
+		let receiver: T::AccountId = account("receiver", 0, SEED);
+		POE::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), receiver)?;
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone())
+	verify {
+		assert!(!PendingTransfers::<T>::contains_key(&bounded::<T>(&proof)));
+	}
+
+	set_claim_metadata {
+		let m in 0 .. T::MaxMetadataLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = create_claim_of::<T>(&caller, T::ProofMaxLength::get())?;
+		let field = vec![0u8; m as usize];
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone(), field.clone(), field.clone(), field)
+	verify {
+		assert_eq!(Proofs::<T>::get(&bounded::<T>(&proof)).unwrap().uri.len(), m as usize);
+	}
+
+	set_co_owners {
+		let c in 1 .. T::MaxCoOwners::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = create_claim_of::<T>(&caller, T::ProofMaxLength::get())?;
+		let co_owners: Vec<T::AccountId> = (1 .. c).map(|i| account("co_owner", i, SEED)).collect();
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone(), co_owners, c)
+	verify {
+		assert_eq!(CoOwners::<T>::get(&bounded::<T>(&proof)).unwrap().0.len(), c as usize);
+	}
+
+	add_delegate {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let permissions = DelegatePermissions { create: true, revoke: true, transfer: true };
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone(), permissions, None)
+	verify {
+		assert!(Delegates::<T>::contains_key(&caller, &delegate));
+	}
+
+	remove_delegate {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let permissions = DelegatePermissions { create: true, revoke: true, transfer: true };
+		POE::<T>::add_delegate(RawOrigin::Signed(caller.clone()).into(), delegate.clone(), permissions, None)?;
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert!(!Delegates::<T>::contains_key(&caller, &delegate));
+	}
+
+	create_claim_for {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value());
+		let caller: T::AccountId = whitelisted_caller();
+		let permissions = DelegatePermissions { create: true, revoke: true, transfer: true };
+		POE::<T>::add_delegate(RawOrigin::Signed(owner.clone()).into(), caller.clone(), permissions, None)?;
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+	}: _(RawOrigin::Signed(caller), owner.clone(), digest, HashAlgorithm::Blake2_256, None)
+	verify {
+		assert_eq!(Proofs::<T>::get(bounded::<T>(&digest)).unwrap().owner, owner);
+	}
+
+	create_claim_on_behalf {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let deadline: T::BlockNumber = 10u32.into();
+		let payload = POE::<T>::on_behalf_payload(digest, HashAlgorithm::Blake2_256, deadline, 0);
+		let (owner, signature) = T::BenchmarkHelper::sign(&payload);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller), digest, HashAlgorithm::Blake2_256, owner.clone(), signature, deadline)
+	verify {
+		assert_eq!(Proofs::<T>::get(bounded::<T>(&digest)).unwrap().owner, owner);
+	}
+
+	freeze_claim {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let proof = create_claim_of::<T>(&owner, T::ProofMaxLength::get())?;
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T>::freeze_claim(DEFAULT_REGISTRY, proof.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(FrozenClaims::<T>::contains_key(&bounded::<T>(&proof)));
+	}
+
+	unfreeze_claim {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let proof = create_claim_of::<T>(&owner, T::ProofMaxLength::get())?;
+		FrozenClaims::<T>::insert(&bounded::<T>(&proof), ());
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T>::unfreeze_claim(DEFAULT_REGISTRY, proof.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!FrozenClaims::<T>::contains_key(&bounded::<T>(&proof)));
+	}
+
+	force_revoke {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let proof = create_claim_of::<T>(&owner, T::ProofMaxLength::get())?;
+		FrozenClaims::<T>::insert(&bounded::<T>(&proof), ());
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T>::force_revoke(DEFAULT_REGISTRY, proof.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Proofs::<T>::contains_key(&bounded::<T>(&proof)));
+	}
+
+	force_transfer {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let proof = create_claim_of::<T>(&owner, T::ProofMaxLength::get())?;
+		let receiver = funded_account::<T>("receiver", 0);
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T>::force_transfer(DEFAULT_REGISTRY, proof.clone(), receiver.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Proofs::<T>::get(&bounded::<T>(&proof)).unwrap().owner, receiver);
+	}
+
+	anchor_merkle_root {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let root = HashAlgorithm::Blake2_256.hash(b"root");
+	}: _(RawOrigin::Signed(caller), root, 1024)
+	verify {
+		assert_eq!(MerkleRoots::<T>::get(bounded::<T>(&root)), Some(1024));
+	}
+
+	create_claims {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let digests: Vec<[u8; 32]> = (0 .. n).map(|i| HashAlgorithm::Blake2_256.hash(&i.to_le_bytes())).collect();
+	}: _(RawOrigin::Signed(caller.clone()), digests, HashAlgorithm::Blake2_256)
+	verify {
+		assert_eq!(ClaimCount::<T>::get(&caller), n);
+	}
+
+	revoke_claims {
+		let n in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let digests: Vec<[u8; 32]> = (0 .. n).map(|i| HashAlgorithm::Blake2_256.hash(&i.to_le_bytes())).collect();
+		POE::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), digests.clone(), HashAlgorithm::Blake2_256)?;
+		let proofs: Vec<Vec<u8>> = digests.iter().map(|digest| digest.to_vec()).collect();
+	}: _(RawOrigin::Signed(caller.clone()), proofs)
+	verify {
+		assert_eq!(ClaimCount::<T>::get(&caller), 0);
+	}
+
+	create_registry {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller.clone()), registry_policy::<T>())
+	verify {
+		assert_eq!(Registries::<T>::get(1).unwrap().admin, caller);
+	}
+
+	set_registry_policy {
+		let caller: T::AccountId = whitelisted_caller();
+		let registry_id = create_registry_of::<T>(&caller)?;
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Anyone, ..registry_policy::<T>() };
+	}: _(RawOrigin::Signed(caller), registry_id, policy)
+	verify {
+		assert_eq!(Registries::<T>::get(registry_id).unwrap().policy, policy);
+	}
+
+	add_registry_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let registry_id = create_registry_of::<T>(&caller)?;
+		let member: T::AccountId = account("member", 0, SEED);
+	}: _(RawOrigin::Signed(caller), registry_id, member.clone())
+	verify {
+		assert!(RegistryMembers::<T>::contains_key(registry_id, &member));
+	}
+
+	remove_registry_member {
+		let caller: T::AccountId = whitelisted_caller();
+		let registry_id = create_registry_of::<T>(&caller)?;
+		let member: T::AccountId = account("member", 0, SEED);
+		POE::<T>::add_registry_member(RawOrigin::Signed(caller.clone()).into(), registry_id, member.clone())?;
+	}: _(RawOrigin::Signed(caller), registry_id, member.clone())
+	verify {
+		assert!(!RegistryMembers::<T>::contains_key(registry_id, &member));
+	}
+
+	create_registry_claim {
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let registry_id = create_registry_of::<T>(&admin)?;
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		POE::<T>::add_registry_member(RawOrigin::Signed(admin).into(), registry_id, caller.clone())?;
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+	}: _(RawOrigin::Signed(caller.clone()), registry_id, digest, HashAlgorithm::Blake2_256)
+	verify {
+		assert_eq!(RegistryClaims::<T>::get(registry_id, &bounded::<T>(&digest)).unwrap().owner, caller);
+	}
+
+	revoke_registry_claim {
+		let s in T::ProofMinLength::get() .. T::ProofMaxLength::get();
+		// the admin revokes the claim of a member, which costs the lookup of the registry
+		let caller: T::AccountId = whitelisted_caller();
+		let registry_id = create_registry_of::<T>(&caller)?;
+		let member: T::AccountId = account("member", 0, SEED);
+		T::Currency::make_free_balance_be(&member, BalanceOf::<T>::max_value());
+		let proof = vec![1u8; s as usize];
+		POE::<T>::do_create_claim(registry_id, member, bounded::<T>(&proof), Some(HashAlgorithm::Blake2_256), None)?;
+	}: _(RawOrigin::Signed(caller), registry_id, proof.clone())
+	verify {
+		assert!(!RegistryClaims::<T>::contains_key(registry_id, &bounded::<T>(&proof)));
+	}
+
+	transfer_registry_claim {
+		let s in T::ProofMinLength::get() .. T::ProofMaxLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let registry_id = create_registry_of::<T>(&caller)?;
+		let proof = vec![1u8; s as usize];
+		POE::<T>::do_create_claim(registry_id, caller.clone(), bounded::<T>(&proof), Some(HashAlgorithm::Blake2_256), None)?;
+		let receiver = funded_account::<T>("receiver", 0);
+		POE::<T>::add_registry_member(RawOrigin::Signed(caller.clone()).into(), registry_id, receiver.clone())?;
+	}: _(RawOrigin::Signed(caller), registry_id, proof.clone(), receiver.clone())
+	verify {
+		assert_eq!(RegistryClaims::<T>::get(registry_id, &bounded::<T>(&proof)).unwrap().owner, receiver);
+	}
+
+	destroy_registry {
+		let caller: T::AccountId = whitelisted_caller();
+		let registry_id = create_registry_of::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), registry_id)
+	verify {
+		assert!(!Registries::<T>::contains_key(registry_id));
+	}
+
+	clear_revocation {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let proof = create_claim_of::<T>(&owner, T::ProofMaxLength::get())?;
+		POE::<T>::revoke_claim(RawOrigin::Signed(owner).into(), proof.clone())?;
+		let cooldown_end = frame_system::Pallet::<T>::block_number() + T::ReclaimCooldown::get();
+		frame_system::Pallet::<T>::set_block_number(cooldown_end);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), DEFAULT_REGISTRY, proof.clone())
+	verify {
+		assert!(!Revoked::<T>::contains_key(&bounded::<T>(&proof)));
+	}
+
+	cancel_action {
+		// every other co-owner approved a transfer, which the last one objects to
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let proof = create_claim_of::<T>(&owner, T::ProofMaxLength::get())?;
+		let caller: T::AccountId = whitelisted_caller();
+		let c = T::MaxCoOwners::get();
+		let mut co_owners: Vec<T::AccountId> = (1 .. c - 1).map(|i| account("co_owner", i, SEED)).collect();
+		co_owners.push(caller.clone());
+		POE::<T>::set_co_owners(RawOrigin::Signed(owner.clone()).into(), proof.clone(), co_owners.clone(), c)?;
+		let receiver = funded_account::<T>("receiver", 0);
+		for who in co_owners.iter().filter(|who| **who != caller).chain(Some(&owner)) {
+			POE::<T>::transfer_claim(RawOrigin::Signed(who.clone()).into(), proof.clone(), receiver.clone())?;
+		}
+	}: _(RawOrigin::Signed(caller), proof.clone())
+	verify {
+		assert!(!Approvals::<T>::contains_key(&bounded::<T>(&proof)));
 	}
 }
 
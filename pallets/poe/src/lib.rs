@@ -15,22 +15,365 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
+/// The prefix of the offchain storage keys under which `create_claim_with_content` indexes the
+/// claimed content.
+pub const CONTENT_KEY_PREFIX: &[u8] = b"poe::content::";
+
+/// The byte prefixed to a document hash to hash it into a Merkle leaf, see `verify_inclusion`.
+pub const MERKLE_LEAF_PREFIX: u8 = 0;
+
+/// The byte prefixed to a pair of Merkle nodes to hash them into their parent.
+pub const MERKLE_NODE_PREFIX: u8 = 1;
+
+/// The context prefixed to the payloads owners sign for `create_claim_on_behalf`, so these
+/// signatures can't pass for signatures of anything else.
+pub const ON_BEHALF_CONTEXT: &[u8] = b"poe::create_claim_on_behalf";
+
+/// The offchain storage key of the content hashing to `digest`.
+pub fn content_key(digest: &[u8]) -> Vec<u8> {
+    [CONTENT_KEY_PREFIX, digest].concat()
+}
+
+/// Listener to the life cycle of claims in every registry, so other pallets can act on it.
+/// Every callback must do a bounded amount of work, as the benchmarks only measure it once.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnClaimChange<AccountId> {
+    /// `owner` claimed `proof` in the registry. Runs within the weight of the creating call.
+    fn on_created(registry_id: RegistryId, owner: &AccountId, proof: &[u8]);
+    /// The claim of `proof` in the registry went from `from` to `to`. Runs within the weight of
+    /// the transferring call.
+    fn on_transferred(registry_id: RegistryId, from: &AccountId, to: &AccountId, proof: &[u8]);
+    /// The claim of `owner` on `proof` in the registry ended, because it was revoked,
+    /// force-revoked or expired. Expiries run in `on_initialize`, so work that grows with the
+    /// chain state belongs in `on_idle`.
+    fn on_revoked(registry_id: RegistryId, owner: &AccountId, proof: &[u8]);
+}
+
+/// Signs the payloads of `create_claim_on_behalf` in benchmarks, since the pallet can't create
+/// an `OffchainSignature` of its own.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Signature, AccountId> {
+    /// A new account without a nonce or funds, and its signature of `payload`.
+    fn sign(payload: &[u8]) -> (AccountId, Signature);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_support::{
+        dispatch::DispatchResult, pallet_prelude::*, transactional,
+        traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency, StorageVersion, UnixTime},
+    };
     use frame_system::pallet_prelude::*;
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+    use sp_io::hashing::{blake2_256, keccak_256, sha2_256};
+    use sp_runtime::{
+        traits::{IdentifyAccount, One, Saturating, Verify, Zero},
+        SaturatedConversion,
+    };
+    use sp_std::convert::TryInto;
     pub use super::*;
     pub use weights::WeightInfo;
 
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// The hash function a claimed digest was computed with.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum HashAlgorithm {
+        /// BLAKE2b with a 256-bit digest.
+        Blake2_256,
+        /// SHA-256.
+        Sha2_256,
+        /// Keccak-256, as used by Ethereum.
+        Keccak256,
+    }
+
+    impl HashAlgorithm {
+        /// Hash `data` with this algorithm.
+        pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+            match self {
+                HashAlgorithm::Blake2_256 => blake2_256(data),
+                HashAlgorithm::Sha2_256 => sha2_256(data),
+                HashAlgorithm::Keccak256 => keccak_256(data),
+            }
+        }
+    }
+
+    /// Information recorded on chain for every claimed proof.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct ClaimInfo<AccountId, BlockNumber, Balance, BoundedString> {
+        /// The account owning the claim.
+        pub owner: AccountId,
+        /// The block at which the claim was created.
+        pub created_at: BlockNumber,
+        /// The time at which the claim was created, in milliseconds since the Unix epoch. Zero
+        /// for claims that predate timestamps.
+        pub timestamp: u64,
+        /// The block at which the claim was created or last transferred.
+        pub block_number: BlockNumber,
+        /// The amount reserved from the owner for holding the claim.
+        pub deposit: Balance,
+        /// The hash function the proof was computed with, unknown for legacy claims.
+        pub hash_algorithm: Option<HashAlgorithm>,
+        /// The block at which the claim is removed automatically, if any.
+        pub expires_at: Option<BlockNumber>,
+        /// A free-form description of the claimed document.
+        pub description: BoundedString,
+        /// The media type of the claimed document, e.g. `application/pdf`.
+        pub content_type: BoundedString,
+        /// Where the claimed document can be retrieved from.
+        pub uri: BoundedString,
+    }
+
+    impl<AccountId, BlockNumber, Balance, BoundedString: Into<Vec<u8>>>
+        ClaimInfo<AccountId, BlockNumber, Balance, BoundedString>
+    {
+        /// Turn the metadata into plain bytes, e.g. for serving the claim over RPC.
+        pub fn into_unbounded(self) -> ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>> {
+            ClaimInfo {
+                owner: self.owner,
+                created_at: self.created_at,
+                timestamp: self.timestamp,
+                block_number: self.block_number,
+                deposit: self.deposit,
+                hash_algorithm: self.hash_algorithm,
+                expires_at: self.expires_at,
+                description: self.description.into(),
+                content_type: self.content_type.into(),
+                uri: self.uri.into(),
+            }
+        }
+    }
+
+    /// What happened to a claim, as recorded in its provenance log.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    pub enum ClaimAction {
+        /// The proof was claimed.
+        Created,
+        /// The claim changed hands.
+        Transferred,
+        /// The claim was revoked or force-revoked.
+        Revoked,
+        /// The claim expired.
+        Expired,
+    }
+
+    /// One entry of the provenance log of a claim.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    pub struct ProvenanceRecord<AccountId, BlockNumber> {
+        /// The owner of the claim after the action, or its last owner once the claim ended.
+        pub owner: AccountId,
+        /// The block at which the action happened.
+        pub block_number: BlockNumber,
+        /// What happened to the claim.
+        pub action: ClaimAction,
+    }
+
+    /// What is left of a revoked claim.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct RevocationRecord<AccountId, BlockNumber, Balance> {
+        /// The owner of the claim when it was revoked.
+        pub owner: AccountId,
+        /// The account that revoked the claim, `None` when revoked by the `ForceOrigin`.
+        pub revoker: Option<AccountId>,
+        /// The block at which the claim was revoked.
+        pub block_number: BlockNumber,
+        /// The part of the claim deposit still reserved from the owner, until the record is
+        /// cleared.
+        pub deposit: Balance,
+    }
+
+    /// Whether a proof is claimed, was claimed and revoked, or has never been claimed.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub enum ClaimStatus<AccountId, BlockNumber, Balance> {
+        /// The proof has never been claimed, or its claim expired.
+        Unclaimed,
+        /// The proof is currently claimed.
+        Claimed,
+        /// The last claim of the proof was revoked.
+        Revoked(RevocationRecord<AccountId, BlockNumber, Balance>),
+    }
+
+    /// An action on a jointly owned claim that needs the approval of its co-owners.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    pub enum JointAction<AccountId> {
+        /// Revoke the claim.
+        Revoke,
+        /// Transfer the claim to the given account.
+        Transfer(AccountId),
+    }
+
+    /// Something a delegate may do with the claims of the owner it acts for.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum ClaimPermission {
+        /// Create claims owned by the owner.
+        Create,
+        /// Revoke claims of the owner.
+        Revoke,
+        /// Transfer claims of the owner.
+        Transfer,
+    }
+
+    /// The permissions granted to a delegate.
+    #[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    pub struct DelegatePermissions {
+        /// May create claims owned by the owner.
+        pub create: bool,
+        /// May revoke claims of the owner.
+        pub revoke: bool,
+        /// May transfer claims of the owner.
+        pub transfer: bool,
+    }
+
+    impl DelegatePermissions {
+        /// Whether these permissions include `permission`.
+        pub fn allows(&self, permission: ClaimPermission) -> bool {
+            match permission {
+                ClaimPermission::Create => self.create,
+                ClaimPermission::Revoke => self.revoke,
+                ClaimPermission::Transfer => self.transfer,
+            }
+        }
+    }
+
+    /// The identifier of a registry of claims.
+    pub type RegistryId = u32;
+
+    /// The registry of the claims in `Proofs`, served by the calls that take no registry id.
+    pub const DEFAULT_REGISTRY: RegistryId = 0;
+
+    /// Who may claim proofs in a registry.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum ClaimPolicy {
+        /// Any account.
+        Anyone,
+        /// The admin and the members of the registry only.
+        Members,
+    }
+
+    /// The rules claims in a registry follow.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct RegistryPolicy {
+        /// Who may claim proofs in the registry.
+        pub claimers: ClaimPolicy,
+        /// The maximum length of the proofs claimed in the registry, at most `ProofMaxLength`.
+        pub max_proof_length: u32,
+    }
+
+    /// A registry of claims, administered by the account that created it.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+    pub struct RegistryInfo<AccountId, Balance> {
+        /// The account which may change the policy and the members of the registry.
+        pub admin: AccountId,
+        /// The amount reserved from the admin for holding the registry.
+        pub deposit: Balance,
+        /// The rules claims in the registry follow.
+        pub policy: RegistryPolicy,
+    }
+
+    pub type ClaimInfoOf<T> = ClaimInfo<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::BlockNumber,
+        BalanceOf<T>,
+        BoundedVec<u8, <T as Config>::MaxMetadataLength>,
+    >;
+
+    pub type RevocationRecordOf<T> = RevocationRecord<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::BlockNumber,
+        BalanceOf<T>,
+    >;
+
+    /// A proof as kept in storage.
+    pub type ProofOf<T> = BoundedVec<u8, <T as Config>::ProofMaxLength>;
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-        type ProofMaxLength: Get<usize>;
-        type ProofMinLength: Get<usize>;
+        /// The maximum length of a proof.
+        #[pallet::constant]
+        type ProofMaxLength: Get<u32>;
+        /// The minimum length of a proof.
+        #[pallet::constant]
+        type ProofMinLength: Get<u32>;
+        /// The maximum length of the description, content type and URI of a claim.
+        #[pallet::constant]
+        type MaxMetadataLength: Get<u32>;
+        /// The currency in which claim deposits are reserved.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// The base amount of currency reserved when creating a claim.
+        #[pallet::constant]
+        type ClaimDepositBase: Get<BalanceOf<Self>>;
+        /// The amount of currency reserved per byte of the proof.
+        #[pallet::constant]
+        type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+        /// The maximum number of expired claims removed in a single block.
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+        /// The maximum number of claims a single account may hold.
+        #[pallet::constant]
+        type MaxClaimsPerAccount: Get<u32>;
+        /// The maximum number of proofs handled by a single batch call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// The number of blocks an offered claim stays open for the receiver to accept.
+        #[pallet::constant]
+        type OfferDuration: Get<Self::BlockNumber>;
+        /// The maximum number of entries in the provenance log of a proof, which keeps the
+        /// latest ones.
+        #[pallet::constant]
+        type MaxHistoryLength: Get<u32>;
+        /// The maximum number of co-owners of a jointly owned claim.
+        #[pallet::constant]
+        type MaxCoOwners: Get<u32>;
+        /// The number of blocks approvals for an action on a jointly owned claim stay valid.
+        #[pallet::constant]
+        type ApprovalPeriod: Get<Self::BlockNumber>;
+        /// The signature with which an owner authorises a claim created on its behalf.
+        type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+        /// The public key verifying an `OffchainSignature`, identifying the owner.
+        type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+        /// Signs payloads as some `SigningPublicKey`, to benchmark `create_claim_on_behalf`.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::OffchainSignature, Self::AccountId>;
+        /// The origin which may freeze, force-revoke or force-transfer any claim.
+        type ForceOrigin: EnsureOrigin<Self::Origin>;
+        /// The source of the wall-clock time recorded with claims.
+        type UnixTime: UnixTime;
+        /// The number of blocks a revoked proof can't be claimed again for.
+        #[pallet::constant]
+        type ReclaimCooldown: Get<Self::BlockNumber>;
+        /// The part of the claim deposit kept reserved while the record of its revocation
+        /// exists, see `clear_revocation`.
+        #[pallet::constant]
+        type RevocationDeposit: Get<BalanceOf<Self>>;
+        /// The maximum length of the content claimed by `create_claim_with_content`.
+        #[pallet::constant]
+        type MaxContentLength: Get<u32>;
+        /// Listener notified when a claim is created, transferred or revoked. It must do a
+        /// bounded amount of work, see `OnClaimChange`.
+        type OnClaimChange: OnClaimChange<Self::AccountId>;
+        /// The amount of currency reserved when creating a registry.
+        #[pallet::constant]
+        type RegistryDeposit: Get<BalanceOf<Self>>;
 
         /// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -38,29 +381,331 @@ pub mod pallet {
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::generate_storage_info]
     pub struct Pallet<T>(_);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Remove the claims expiring at block `n`.
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            if !ClaimExpiries::<T>::contains_key(n) {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let expiring = ClaimExpiries::<T>::take(n);
+            let count = expiring.len() as u32;
+            for proof in expiring {
+                Self::expire_claim(n, proof);
+            }
+            T::WeightInfo::on_initialize(count)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::migrate::<T>()
+        }
+
+        fn integrity_test() {
+            assert!(T::MaxExpiriesPerBlock::get() > 0, "claims must be able to expire");
+        }
+    }
+
     // The pallet's runtime storage items.
     // https://substrate.dev/docs/en/knowledgebase/runtime/storage
     #[pallet::storage]
     #[pallet::getter(fn proofs)]
     // Learn more about declaring storage items:
     // https://substrate.dev/docs/en/knowledgebase/runtime/storage#declaring-storage-items
-    pub type Proofs<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, (T::AccountId, T::BlockNumber), ValueQuery>;
+    pub type Proofs<T: Config> = StorageMap<_, Blake2_128Concat, ProofOf<T>, ClaimInfoOf<T>>;
+
+    /// The claims to be removed at a given block.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_expiries)]
+    pub type ClaimExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        BoundedVec<ProofOf<T>, T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The proofs claimed by every account in the default registry.
+    #[pallet::storage]
+    pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Blake2_128Concat, ProofOf<T>,
+        (),
+    >;
+
+    /// The number of proofs claimed by every account, in all registries.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_count)]
+    pub type ClaimCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Merkle roots anchored by `anchor_merkle_root`, with the number of leaves they commit to.
+    #[pallet::storage]
+    #[pallet::getter(fn merkle_roots)]
+    pub type MerkleRoots<T: Config> = StorageMap<_, Blake2_128Concat, ProofOf<T>, u32>;
+
+    /// Claims offered to a receiver, with the block at which the offer lapses.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfers)]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProofOf<T>,
+        (T::AccountId, T::BlockNumber),
+    >;
+
+    /// The chain of custody of every proof, oldest entry first. It outlives the claims of the
+    /// proof, and drops its oldest entry to make room once `MaxHistoryLength` is reached.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_history)]
+    pub type ClaimHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProofOf<T>,
+        BoundedVec<ProvenanceRecord<T::AccountId, T::BlockNumber>, T::MaxHistoryLength>,
+        ValueQuery,
+    >;
+
+    /// The co-owners of jointly owned claims, with the number of them that must approve an action.
+    #[pallet::storage]
+    #[pallet::getter(fn co_owners)]
+    pub type CoOwners<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProofOf<T>,
+        (BoundedVec<T::AccountId, T::MaxCoOwners>, u32),
+    >;
+
+    /// The open action on a jointly owned claim, with the co-owners that approved it, those
+    /// that object to it and the block at which their approvals lapse. A claim has at most one
+    /// open action at a time.
+    #[pallet::storage]
+    #[pallet::getter(fn approvals)]
+    pub type Approvals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProofOf<T>,
+        (
+            JointAction<T::AccountId>,
+            BoundedVec<T::AccountId, T::MaxCoOwners>,
+            BoundedVec<T::AccountId, T::MaxCoOwners>,
+            T::BlockNumber,
+        ),
+    >;
+
+    /// The delegates managing claims for an owner, with their permissions and the block at
+    /// which the delegation lapses, if any.
+    #[pallet::storage]
+    #[pallet::getter(fn delegates)]
+    pub type Delegates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Blake2_128Concat, T::AccountId,
+        (DelegatePermissions, Option<T::BlockNumber>),
+    >;
+
+    /// The number of claims created on behalf of every account, signed into the next one to
+    /// prevent replays.
+    #[pallet::storage]
+    #[pallet::getter(fn nonce)]
+    pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Disputed claims in the default registry, which their owner can neither transfer nor
+    /// revoke.
+    #[pallet::storage]
+    pub type FrozenClaims<T: Config> = StorageMap<_, Blake2_128Concat, ProofOf<T>, ()>;
+
+    /// The revocation of proofs whose last claim in the default registry was revoked.
+    #[pallet::storage]
+    #[pallet::getter(fn revoked)]
+    pub type Revoked<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProofOf<T>,
+        RevocationRecordOf<T>,
+    >;
+
+    /// The registries created by accounts. The default registry has no entry.
+    #[pallet::storage]
+    #[pallet::getter(fn registries)]
+    pub type Registries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RegistryId,
+        RegistryInfo<T::AccountId, BalanceOf<T>>,
+    >;
+
+    /// The id of the last registry created.
+    #[pallet::storage]
+    pub type LastRegistryId<T: Config> = StorageValue<_, RegistryId, ValueQuery>;
+
+    /// The accounts allowed to claim in registries with the `Members` policy.
+    #[pallet::storage]
+    pub type RegistryMembers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat, RegistryId,
+        Blake2_128Concat, T::AccountId,
+        (),
+    >;
+
+    /// The claims of all registries but the default one, by registry and proof.
+    #[pallet::storage]
+    #[pallet::getter(fn registry_claims)]
+    pub type RegistryClaims<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat, RegistryId,
+        Blake2_128Concat, ProofOf<T>,
+        ClaimInfoOf<T>,
+    >;
+
+    /// The proofs claimed by every account in the registries but the default one.
+    #[pallet::storage]
+    pub type RegistryClaimsByOwner<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Blake2_128Concat, (RegistryId, ProofOf<T>),
+        (),
+    >;
+
+    /// Disputed claims in the registries but the default one, see `FrozenClaims`.
+    #[pallet::storage]
+    pub type FrozenRegistryClaims<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat, RegistryId,
+        Blake2_128Concat, ProofOf<T>,
+        (),
+    >;
+
+    /// The revocation of proofs whose last claim in a registry but the default one was revoked,
+    /// see `Revoked`.
+    #[pallet::storage]
+    #[pallet::getter(fn revoked_registry_claims)]
+    pub type RevokedRegistryClaims<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat, RegistryId,
+        Blake2_128Concat, ProofOf<T>,
+        RevocationRecordOf<T>,
+    >;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Claims present from block 0, as `(proof, owner)` pairs. They hold no deposit.
+        pub claims: Vec<(Vec<u8>, T::AccountId)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { claims: Default::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for (proof, owner) in self.claims.iter() {
+                let proof = Pallet::<T>::bounded_proof(proof.clone())
+                    .expect("Genesis claim proof must be within ProofMinLength and ProofMaxLength");
+                assert!(!Proofs::<T>::contains_key(&proof), "Duplicate proof in genesis claims");
+                assert!(
+                    Pallet::<T>::claim_count(owner) < T::MaxClaimsPerAccount::get(),
+                    "Genesis claims exceed MaxClaimsPerAccount",
+                );
+
+                Proofs::<T>::insert(&proof, ClaimInfo {
+                    owner: owner.clone(),
+                    created_at: Zero::zero(),
+                    timestamp: Zero::zero(),
+                    block_number: Zero::zero(),
+                    deposit: Zero::zero(),
+                    hash_algorithm: None,
+                    expires_at: None,
+                    description: Default::default(),
+                    content_type: Default::default(),
+                    uri: Default::default(),
+                });
+                Pallet::<T>::add_owned(DEFAULT_REGISTRY, owner, &proof);
+                Pallet::<T>::record_provenance(&proof, owner.clone(), Zero::zero(), ClaimAction::Created);
+            }
+        }
+    }
 
     // Pallets use events to inform users when important changes are made.
     // https://substrate.dev/docs/en/knowledgebase/runtime/events
     #[pallet::event]
-    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Event documentation should end with an array that provides descriptive names for event
-        /// Event emitted when a proof has been claimed. [who, claim]
-        ClaimCreated(T::AccountId, Vec<u8>),
+        /// Event emitted when a proof has been claimed. [who, claim, timestamp]
+        ClaimCreated(T::AccountId, Vec<u8>, u64),
         /// Event emitted when a claim is revoked by the owner. [who, claim]
         ClaimRevoked(T::AccountId, Vec<u8>),
-        // Event emitted when a claim is transferred by the owner. [owner, receiver,claim]
-        ClaimTransferred(T::AccountId, T::AccountId, Vec<u8>),
+        // Event emitted when a claim is transferred by the owner. [owner, receiver, claim, timestamp]
+        ClaimTransferred(T::AccountId, T::AccountId, Vec<u8>, u64),
+        /// Event emitted when the owner updates the metadata of a claim. [who, claim]
+        ClaimMetadataSet(T::AccountId, Vec<u8>),
+        /// Event emitted when the deposit of a claim is reserved. [who, amount]
+        DepositReserved(T::AccountId, BalanceOf<T>),
+        /// Event emitted when the deposit of a claim is returned. [who, amount]
+        DepositUnreserved(T::AccountId, BalanceOf<T>),
+        /// Event emitted when the deposit of a claim moves with its ownership. [from, to, amount]
+        DepositRepatriated(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Event emitted when a claim reaches its expiry block and is removed. [owner, claim]
+        ClaimExpired(T::AccountId, Vec<u8>),
+        /// Event emitted when a Merkle root has been claimed. [who, root, leaf_count]
+        MerkleRootAnchored(T::AccountId, Vec<u8>, u32),
+        /// Event emitted when the owner offers a claim to someone else. [owner, receiver, claim]
+        ClaimOffered(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when the owner withdraws an offer. [owner, claim]
+        ClaimOfferCancelled(T::AccountId, Vec<u8>),
+        /// Event emitted when the owner shares a claim with co-owners. [owner, claim, threshold]
+        CoOwnersSet(T::AccountId, Vec<u8>, u32),
+        /// Event emitted when a co-owner approves an action on a jointly owned claim.
+        /// [who, claim, approvals]
+        ActionApproved(T::AccountId, Vec<u8>, u32),
+        /// Event emitted when a co-owner withdraws its approval of an action on a jointly owned
+        /// claim. [who, claim, approvals]
+        ApprovalWithdrawn(T::AccountId, Vec<u8>, u32),
+        /// Event emitted when a co-owner objects to an action on a jointly owned claim.
+        /// [who, claim, objections]
+        ActionObjected(T::AccountId, Vec<u8>, u32),
+        /// Event emitted when the open action on a jointly owned claim is cancelled. [claim]
+        ActionCancelled(Vec<u8>),
+        /// Event emitted when an owner authorises a delegate. [owner, delegate]
+        DelegateAdded(T::AccountId, T::AccountId),
+        /// Event emitted when an owner withdraws a delegation. [owner, delegate]
+        DelegateRemoved(T::AccountId, T::AccountId),
+        /// Event emitted when a delegate acts on a claim for its owner. [delegate, owner, claim]
+        DelegateActed(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when a disputed claim is frozen. [registry, claim]
+        ClaimFrozen(RegistryId, Vec<u8>),
+        /// Event emitted when a claim is released from a freeze. [registry, claim]
+        ClaimUnfrozen(RegistryId, Vec<u8>),
+        /// Event emitted when a claim is revoked by the `ForceOrigin`. [owner, registry, claim]
+        ClaimForceRevoked(T::AccountId, RegistryId, Vec<u8>),
+        /// Event emitted when a registry is created. [admin, registry]
+        RegistryCreated(T::AccountId, RegistryId),
+        /// Event emitted when the policy of a registry changes. [registry, policy]
+        RegistryPolicySet(RegistryId, RegistryPolicy),
+        /// Event emitted when an account may claim in a registry. [registry, who]
+        RegistryMemberAdded(RegistryId, T::AccountId),
+        /// Event emitted when an account may no longer claim in a registry. [registry, who]
+        RegistryMemberRemoved(RegistryId, T::AccountId),
+        /// Event emitted when a proof is claimed in a registry. [who, registry, claim, timestamp]
+        RegistryClaimCreated(T::AccountId, RegistryId, Vec<u8>, u64),
+        /// Event emitted when a claim in a registry is revoked. [who, registry, claim]
+        RegistryClaimRevoked(T::AccountId, RegistryId, Vec<u8>),
+        /// Event emitted when a claim in a registry is transferred. [from, to, registry, claim]
+        RegistryClaimTransferred(T::AccountId, T::AccountId, RegistryId, Vec<u8>),
+        /// Event emitted when a registry is destroyed. [admin, registry]
+        RegistryDestroyed(T::AccountId, RegistryId),
+        /// Event emitted when the record of a revocation is cleared. [registry, claim]
+        RevocationCleared(RegistryId, Vec<u8>),
     }
 
     // Errors inform users that something went wrong.
@@ -76,6 +721,72 @@ pub mod pallet {
         ProofTooLong,
         /// The length of claim is too short
         ProofTooShort,
+        /// The description, content type or URI of a claim is too long
+        MetadataTooLong,
+        /// The caller can't afford the deposit of the claim
+        InsufficientBalanceToReserve,
+        /// The deposit of the claim can't be moved to the receiver
+        RepatriateFailed,
+        /// The expiry block of the claim is not in the future
+        InvalidExpiry,
+        /// The account already holds `MaxClaimsPerAccount` claims
+        TooManyClaims,
+        /// The batch holds more than `MaxBatchSize` proofs
+        BatchTooLarge,
+        /// The Merkle tree has no leaves
+        EmptyMerkleTree,
+        /// The claim has not been offered to anyone
+        NoSuchOffer,
+        /// The claim has been offered to another account
+        NotOfferReceiver,
+        /// The offer is no longer open
+        OfferExpired,
+        /// The claim is owned jointly, so its co-owners must approve the action
+        JointlyOwned,
+        /// More than `MaxCoOwners` co-owners were given
+        TooManyCoOwners,
+        /// The threshold is zero or exceeds the number of co-owners
+        InvalidThreshold,
+        /// The co-owner has already approved this action
+        AlreadyApproved,
+        /// Another action on the jointly owned claim awaits approval
+        ActionPending,
+        /// No action on the jointly owned claim awaits approval
+        NoPendingAction,
+        /// The co-owner has already objected to this action
+        AlreadyObjected,
+        /// The account is not an authorised delegate of the owner
+        NotDelegate,
+        /// The signature does not match the owner, claim and nonce
+        BadSignature,
+        /// The signed deadline has passed
+        SignatureExpired,
+        /// The claim is frozen while disputed
+        ClaimFrozen,
+        /// The claim is not frozen
+        NotFrozen,
+        /// The proof was revoked less than `ReclaimCooldown` blocks ago
+        RevokedRecently,
+        /// The content is longer than `MaxContentLength`
+        ContentTooLong,
+        /// The registry does not exist
+        NoSuchRegistry,
+        /// The caller is not the admin of the registry
+        NotRegistryAdmin,
+        /// The policy of the registry does not allow the account to claim
+        NotAllowedToClaim,
+        /// The maximum proof length of the policy is outside `ProofMinLength` and `ProofMaxLength`
+        InvalidPolicy,
+        /// No registry id is left
+        NoRegistryIdAvailable,
+        /// The account is already a member of the registry
+        AlreadyMember,
+        /// The account is not a member of the registry
+        NotMember,
+        /// The registry still holds claims or members
+        RegistryInUse,
+        /// The proof has no record of a revocation
+        NotRevoked,
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -83,81 +794,1214 @@ pub mod pallet {
     // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// create claim for the invoker
-        #[pallet::weight(<T as Config>::WeightInfo::create_claim(proof.len() as u32))]
-        pub fn create_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResult {
+        /// create claim for the invoker on a document `digest` computed with `algorithm`,
+        /// optionally removed once `expires_at` is reached, or at the next block with room for it
+        #[pallet::weight(<T as Config>::WeightInfo::create_claim())]
+        pub fn create_claim(
+            origin: OriginFor<T>,
+            digest: [u8; 32],
+            algorithm: HashAlgorithm,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResult {
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
-            // verify the claim is in bound
-            ensure!(proof.len() >= T::ProofMinLength::get(), Error::<T>::ProofTooShort);
-            ensure!(proof.len() <= T::ProofMaxLength::get(), Error::<T>::ProofTooLong);
+            let proof = Self::bounded_proof(digest.to_vec())?;
+            Self::do_create_claim(DEFAULT_REGISTRY, sender, proof, Some(algorithm), expires_at)
+        }
 
-            // Verify that the specified proof has not already been claimed.
-            ensure!(!Proofs::<T>::contains_key(&proof), Error::<T>::ProofAlreadyClaimed);
+        /// create claim for the invoker on the Blake2-256 hash of `content`, and index the content
+        /// off-chain so that nodes running with offchain indexing enabled can serve it
+        #[pallet::weight(<T as Config>::WeightInfo::create_claim_with_content(content.len() as u32))]
+        pub fn create_claim_with_content(origin: OriginFor<T>, content: Vec<u8>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
 
-            // Get the block number from the FRAME System module.
-            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(content.len() <= T::MaxContentLength::get() as usize, Error::<T>::ContentTooLong);
 
-            // Store the proof with the sender and block number.
-            Proofs::<T>::insert(&proof, (&sender, current_block));
+            let digest = HashAlgorithm::Blake2_256.hash(&content);
+            let proof = Self::bounded_proof(digest.to_vec())?;
+            Self::do_create_claim(DEFAULT_REGISTRY, sender, proof, Some(HashAlgorithm::Blake2_256), None)?;
 
-            // Emit an event that the claim was created.
-            Self::deposit_event(Event::ClaimCreated(sender, proof));
+            // Only the hash is kept in state, the content itself goes to the offchain database.
+            sp_io::offchain_index::set(&content_key(&digest), &content);
             Ok(())
         }
 
-        /// The claim owner revoke its claim
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        /// The claim owner, or a delegate acting for it, revoke its claim
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_claim(proof.len() as u32))]
+        #[transactional]
         pub fn revoke_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResult {
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
-            
-            // Verify that the specified proof has been claimed.
-            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
 
-            // Get owner of the claim.
-            let (owner, _) = Proofs::<T>::get(&proof);
+            Self::do_revoke_claim(DEFAULT_REGISTRY, sender, Self::claimed_proof(proof)?)
+        }
+
+        /// The claim owner, or a delegate acting for it, transfer claim to someone else. On a
+        /// jointly owned claim this records the approval of the co-owner, and the transfer goes
+        /// through once enough co-owners approved it.
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_claim(proof.len() as u32))]
+        #[transactional]
+        pub fn transfer_claim(origin: OriginFor<T>, proof: Vec<u8>, account_id: T::AccountId) -> DispatchResult {
+            // Check that the extrinsic was signed and get the signer.
+            // This function will return an error if the extrinsic is not signed.
+            // https://substrate.dev/docs/en/knowledgebase/runtime/origin
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            // Verify that the specified proof has been claimed and get its owner.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Verify the claim is not disputed.
+            ensure!(!FrozenClaims::<T>::contains_key(&proof), Error::<T>::ClaimFrozen);
+
+            // An authorised delegate acts for the owner.
+            let sender = Self::acting_for(sender, &claim.owner, &proof, ClaimPermission::Transfer);
+
+            if CoOwners::<T>::contains_key(&proof) {
+                // Wait for the approval of enough co-owners.
+                if !Self::approve(&sender, &proof, &JointAction::Transfer(account_id.clone()))? {
+                    return Ok(())
+                }
+            } else {
+                // Verify that sender of the current call is the claim owner.
+                ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
+            }
+
+            Self::do_transfer_claim(DEFAULT_REGISTRY, proof, claim, account_id)
+        }
+
+        /// The claim owner offers its claim to someone else, who has `OfferDuration` blocks to
+        /// accept it
+        #[pallet::weight(<T as Config>::WeightInfo::offer_claim())]
+        pub fn offer_claim(origin: OriginFor<T>, proof: Vec<u8>, account_id: T::AccountId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            // Verify that the specified proof has been claimed and get its owner.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
 
             // Verify that sender of the current call is the claim owner.
-            ensure!(sender == owner, Error::<T>::NotProofOwner);
+            ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
 
-            // Remove claim from storage.
-            Proofs::<T>::remove(&proof);
+            // A jointly owned claim only changes hands with the approval of its co-owners.
+            ensure!(!CoOwners::<T>::contains_key(&proof), Error::<T>::JointlyOwned);
 
-            // Emit an event that the claim was erased.
-            Self::deposit_event(Event::ClaimRevoked(sender, proof));
+            // Verify the claim is not disputed.
+            ensure!(!FrozenClaims::<T>::contains_key(&proof), Error::<T>::ClaimFrozen);
+
+            // Replace any previous offer for the claim.
+            let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(T::OfferDuration::get());
+            PendingTransfers::<T>::insert(&proof, (account_id.clone(), expires_at));
+
+            Self::deposit_event(Event::ClaimOffered(sender, account_id, proof.into()));
             Ok(())
         }
 
-        /// The claim owner transfer claim to someone else 
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        pub fn transfer_claim(origin: OriginFor<T>, proof: Vec<u8>, account_id: T::AccountId) -> DispatchResult {
-            // Check that the extrinsic was signed and get the signer.
-            // This function will return an error if the extrinsic is not signed.
-            // https://substrate.dev/docs/en/knowledgebase/runtime/origin
+        /// The receiver of an offer takes over the claim
+        #[pallet::weight(<T as Config>::WeightInfo::accept_claim())]
+        pub fn accept_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
-            // Verify that the specified proof has been claimed.
-            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+            // Verify the offer is addressed to the sender and still open.
+            let proof = Self::claimed_proof(proof)?;
+            let (receiver, expires_at) = PendingTransfers::<T>::get(&proof).ok_or(Error::<T>::NoSuchOffer)?;
+            ensure!(sender == receiver, Error::<T>::NotOfferReceiver);
+            ensure!(frame_system::Pallet::<T>::block_number() < expires_at, Error::<T>::OfferExpired);
+
+            // Offers are dropped with their claim, so the claim still exists.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
 
-            // Get owner of the claim.
-            let (owner, _block_number) = Proofs::<T>::get(&proof);
+            // Verify the claim is not disputed.
+            ensure!(!FrozenClaims::<T>::contains_key(&proof), Error::<T>::ClaimFrozen);
+
+            Self::do_transfer_claim(DEFAULT_REGISTRY, proof, claim, sender)
+        }
+
+        /// The claim owner withdraws its offer
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+        pub fn cancel_offer(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            // Verify that the specified proof has been claimed and get its owner.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
 
             // Verify that sender of the current call is the claim owner.
-            ensure!(sender == owner, Error::<T>::NotProofOwner);
+            ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
 
-            // Overwrite claim in storage
-            Proofs::<T>::insert(&proof, (account_id.clone(), frame_system::Pallet::<T>::block_number()));
+            ensure!(PendingTransfers::<T>::contains_key(&proof), Error::<T>::NoSuchOffer);
+            PendingTransfers::<T>::remove(&proof);
 
-            // Emit an event that the claim was erased.
-            Self::deposit_event(Event::ClaimTransferred(sender, account_id, proof));
+            Self::deposit_event(Event::ClaimOfferCancelled(sender, proof.into()));
+            Ok(())
+        }
+
+        /// The claim owner describes the document behind its claim
+        #[pallet::weight(<T as Config>::WeightInfo::set_claim_metadata(
+            description.len().max(content_type.len()).max(uri.len()) as u32
+        ))]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            description: Vec<u8>,
+            content_type: Vec<u8>,
+            uri: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            // Verify that the specified proof has been claimed and get its owner.
+            let mut claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Verify that sender of the current call is the claim owner.
+            ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
+
+            // Verify every field is in bound
+            claim.description = description.try_into().map_err(|_| Error::<T>::MetadataTooLong)?;
+            claim.content_type = content_type.try_into().map_err(|_| Error::<T>::MetadataTooLong)?;
+            claim.uri = uri.try_into().map_err(|_| Error::<T>::MetadataTooLong)?;
+            Proofs::<T>::insert(&proof, claim);
 
+            Self::deposit_event(Event::ClaimMetadataSet(sender, proof.into()));
             Ok(())
         }
+
+        /// The claim owner shares its claim with `co_owners`, after which revoking or
+        /// transferring it needs the approval of `threshold` of them. The owner is a co-owner
+        /// whether listed or not.
+        #[pallet::weight(<T as Config>::WeightInfo::set_co_owners((co_owners.len() as u32).saturating_add(1)))]
+        pub fn set_co_owners(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            co_owners: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            // Verify that the specified proof has been claimed and get its owner.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Verify that sender of the current call is the claim owner.
+            ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
+
+            // The co-owners of a jointly owned claim can't be changed by one of them alone.
+            ensure!(!CoOwners::<T>::contains_key(&proof), Error::<T>::JointlyOwned);
+
+            let mut co_owners = co_owners;
+            co_owners.insert(0, sender.clone());
+            co_owners.sort();
+            co_owners.dedup();
+            ensure!(threshold > 0 && threshold as usize <= co_owners.len(), Error::<T>::InvalidThreshold);
+            let co_owners: BoundedVec<_, T::MaxCoOwners> = co_owners.try_into()
+                .map_err(|_| Error::<T>::TooManyCoOwners)?;
+
+            // An open offer would let the claim change hands without approval.
+            PendingTransfers::<T>::remove(&proof);
+            CoOwners::<T>::insert(&proof, (co_owners, threshold));
+
+            Self::deposit_event(Event::CoOwnersSet(sender, proof.into(), threshold));
+            Ok(())
+        }
+
+        /// Authorise `delegate` to manage the claims of the invoker with `permissions`, until
+        /// `expires_at` if given. Replaces any previous delegation to the same account.
+        #[pallet::weight(<T as Config>::WeightInfo::add_delegate())]
+        pub fn add_delegate(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            permissions: DelegatePermissions,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            // Verify the delegation does not lapse before it exists.
+            if let Some(at) = expires_at {
+                ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+            }
+
+            Delegates::<T>::insert(&sender, &delegate, (permissions, expires_at));
+
+            Self::deposit_event(Event::DelegateAdded(sender, delegate));
+            Ok(())
+        }
+
+        /// Withdraw the authorisation of `delegate`
+        #[pallet::weight(<T as Config>::WeightInfo::remove_delegate())]
+        pub fn remove_delegate(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Delegates::<T>::contains_key(&sender, &delegate), Error::<T>::NotDelegate);
+            Delegates::<T>::remove(&sender, &delegate);
+
+            Self::deposit_event(Event::DelegateRemoved(sender, delegate));
+            Ok(())
+        }
+
+        /// An authorised delegate creates a claim owned by `owner`, who pays the deposit, see
+        /// `create_claim`
+        #[pallet::weight(<T as Config>::WeightInfo::create_claim_for())]
+        pub fn create_claim_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            digest: [u8; 32],
+            algorithm: HashAlgorithm,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            // Verify that sender of the current call may create claims for the owner.
+            ensure!(Self::is_delegate(&owner, &sender, ClaimPermission::Create), Error::<T>::NotDelegate);
+
+            let proof = Self::bounded_proof(digest.to_vec())?;
+            Self::do_create_claim(DEFAULT_REGISTRY, owner.clone(), proof.clone(), Some(algorithm), expires_at)?;
+
+            Self::deposit_event(Event::DelegateActed(sender, owner, proof.into()));
+            Ok(())
+        }
+
+        /// create a claim owned by `owner`, who signed the `on_behalf_payload` of
+        /// `(digest, algorithm, deadline)` off-chain with its current `nonce`. The invoker pays
+        /// the fee and hands the deposit over to the owner, along with the existential deposit
+        /// of an owner without an account, so the owner needs no funds of its own.
+        #[pallet::weight(<T as Config>::WeightInfo::create_claim_on_behalf())]
+        #[transactional]
+        pub fn create_claim_on_behalf(
+            origin: OriginFor<T>,
+            digest: [u8; 32],
+            algorithm: HashAlgorithm,
+            owner: T::AccountId,
+            signature: T::OffchainSignature,
+            deadline: T::BlockNumber,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            // Verify the owner signed this very claim, and not too long ago.
+            ensure!(frame_system::Pallet::<T>::block_number() <= deadline, Error::<T>::SignatureExpired);
+            let nonce = Nonces::<T>::get(&owner);
+            let payload = Self::on_behalf_payload(digest, algorithm, deadline, nonce);
+            ensure!(signature.verify(&payload[..], &owner), Error::<T>::BadSignature);
+            Nonces::<T>::insert(&owner, nonce.saturating_add(1));
+
+            // Fund the deposit of the owner, and its existential deposit if it has no account yet.
+            let proof = Self::bounded_proof(digest.to_vec())?;
+            let mut funds = Self::claim_deposit(&proof);
+            if T::Currency::total_balance(&owner).is_zero() {
+                funds = funds.saturating_add(T::Currency::minimum_balance());
+            }
+            T::Currency::transfer(&sender, &owner, funds, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::InsufficientBalanceToReserve)?;
+
+            Self::do_create_claim(DEFAULT_REGISTRY, owner, proof, Some(algorithm), None)
+        }
+
+        /// Freeze a disputed claim in a registry, so its owner can neither transfer nor revoke it
+        #[pallet::weight(<T as Config>::WeightInfo::freeze_claim())]
+        pub fn freeze_claim(origin: OriginFor<T>, registry_id: RegistryId, proof: Vec<u8>) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            ensure!(Self::claim_in(registry_id, &proof).is_some(), Error::<T>::NoSuchProof);
+            ensure!(!Self::is_frozen(registry_id, &proof), Error::<T>::ClaimFrozen);
+            if registry_id == DEFAULT_REGISTRY {
+                FrozenClaims::<T>::insert(&proof, ());
+            } else {
+                FrozenRegistryClaims::<T>::insert(registry_id, &proof, ());
+            }
+
+            Self::deposit_event(Event::ClaimFrozen(registry_id, proof.into()));
+            Ok(())
+        }
+
+        /// Release a claim in a registry from a freeze
+        #[pallet::weight(<T as Config>::WeightInfo::unfreeze_claim())]
+        pub fn unfreeze_claim(origin: OriginFor<T>, registry_id: RegistryId, proof: Vec<u8>) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let proof: ProofOf<T> = proof.try_into().map_err(|_| Error::<T>::NotFrozen)?;
+            ensure!(Self::is_frozen(registry_id, &proof), Error::<T>::NotFrozen);
+            if registry_id == DEFAULT_REGISTRY {
+                FrozenClaims::<T>::remove(&proof);
+            } else {
+                FrozenRegistryClaims::<T>::remove(registry_id, &proof);
+            }
+
+            Self::deposit_event(Event::ClaimUnfrozen(registry_id, proof.into()));
+            Ok(())
+        }
+
+        /// Revoke any claim in a registry, frozen or not, returning the deposit to its owner
+        #[pallet::weight(<T as Config>::WeightInfo::force_revoke())]
+        pub fn force_revoke(origin: OriginFor<T>, registry_id: RegistryId, proof: Vec<u8>) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            let claim = Self::claim_in(registry_id, &proof).ok_or(Error::<T>::NoSuchProof)?;
+            Self::remove_claim(registry_id, &proof, &claim, ClaimAction::Revoked);
+            Self::record_revocation(registry_id, &proof, &claim, None);
+
+            Self::deposit_event(Event::ClaimForceRevoked(claim.owner, registry_id, proof.into()));
+            Ok(())
+        }
+
+        /// Transfer any claim in a registry, frozen or not, to `account_id`, whatever the policy
+        /// of the registry. A freeze stays in place.
+        #[pallet::weight(<T as Config>::WeightInfo::force_transfer())]
+        pub fn force_transfer(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            proof: Vec<u8>,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            let claim = Self::claim_in(registry_id, &proof).ok_or(Error::<T>::NoSuchProof)?;
+            Self::do_transfer_claim(registry_id, proof, claim, account_id)
+        }
+
+        /// claim the Merkle root of `leaf_count` document hashes, see `verify_inclusion`
+        #[pallet::weight(<T as Config>::WeightInfo::anchor_merkle_root())]
+        pub fn anchor_merkle_root(origin: OriginFor<T>, root: [u8; 32], leaf_count: u32) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(leaf_count > 0, Error::<T>::EmptyMerkleTree);
+
+            let proof = Self::bounded_proof(root.to_vec())?;
+            Self::do_create_claim(DEFAULT_REGISTRY, sender.clone(), proof.clone(), Some(HashAlgorithm::Blake2_256), None)?;
+            MerkleRoots::<T>::insert(&proof, leaf_count);
+
+            Self::deposit_event(Event::MerkleRootAnchored(sender, proof.into(), leaf_count));
+            Ok(())
+        }
+
+        /// create a claim for every digest in the batch, or none of them if any fails
+        #[pallet::weight(<T as Config>::WeightInfo::create_claims(digests.len() as u32))]
+        #[transactional]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            digests: Vec<[u8; 32]>,
+            algorithm: HashAlgorithm,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            // verify the batch is in bound
+            ensure!(digests.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+            let proofs = digests.iter()
+                .map(|digest| Self::bounded_proof(digest.to_vec()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for proof in proofs {
+                Self::do_create_claim(DEFAULT_REGISTRY, sender.clone(), proof, Some(algorithm), None)?;
+            }
+            Ok(())
+        }
+
+        /// revoke every claim in the batch, or none of them if any fails
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_claims(proofs.len() as u32))]
+        #[transactional]
+        pub fn revoke_claims(origin: OriginFor<T>, proofs: Vec<Vec<u8>>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            // verify the batch is in bound
+            ensure!(proofs.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+            let proofs = proofs.into_iter()
+                .map(Self::bounded_proof)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for proof in proofs {
+                Self::do_revoke_claim(DEFAULT_REGISTRY, sender.clone(), proof)?;
+            }
+            Ok(())
+        }
+
+        /// Create a registry administered by the invoker, reserving `RegistryDeposit`
+        #[pallet::weight(<T as Config>::WeightInfo::create_registry())]
+        pub fn create_registry(origin: OriginFor<T>, policy: RegistryPolicy) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_policy_valid(&policy)?;
+            let registry_id = LastRegistryId::<T>::get()
+                .checked_add(1)
+                .ok_or(Error::<T>::NoRegistryIdAvailable)?;
+
+            // Reserve the deposit for holding the registry.
+            let deposit = T::RegistryDeposit::get();
+            T::Currency::reserve(&sender, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalanceToReserve)?;
+            Self::deposit_event(Event::DepositReserved(sender.clone(), deposit));
+
+            LastRegistryId::<T>::put(registry_id);
+            Registries::<T>::insert(registry_id, RegistryInfo { admin: sender.clone(), deposit, policy });
+
+            Self::deposit_event(Event::RegistryCreated(sender, registry_id));
+            Ok(())
+        }
+
+        /// The registry admin changes its policy. Claims made under the former policy are kept.
+        #[pallet::weight(<T as Config>::WeightInfo::set_registry_policy())]
+        pub fn set_registry_policy(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            policy: RegistryPolicy,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_policy_valid(&policy)?;
+            Registries::<T>::try_mutate(registry_id, |registry| -> DispatchResult {
+                let registry = registry.as_mut().ok_or(Error::<T>::NoSuchRegistry)?;
+                ensure!(registry.admin == sender, Error::<T>::NotRegistryAdmin);
+                registry.policy = policy;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RegistryPolicySet(registry_id, policy));
+            Ok(())
+        }
+
+        /// The registry admin allows `who` to claim in a registry with the `Members` policy
+        #[pallet::weight(<T as Config>::WeightInfo::add_registry_member())]
+        pub fn add_registry_member(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_registry_admin(registry_id, &sender)?;
+            ensure!(!RegistryMembers::<T>::contains_key(registry_id, &who), Error::<T>::AlreadyMember);
+            RegistryMembers::<T>::insert(registry_id, &who, ());
+
+            Self::deposit_event(Event::RegistryMemberAdded(registry_id, who));
+            Ok(())
+        }
+
+        /// The registry admin no longer allows `who` to claim. Its claims are kept.
+        #[pallet::weight(<T as Config>::WeightInfo::remove_registry_member())]
+        pub fn remove_registry_member(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_registry_admin(registry_id, &sender)?;
+            ensure!(RegistryMembers::<T>::contains_key(registry_id, &who), Error::<T>::NotMember);
+            RegistryMembers::<T>::remove(registry_id, &who);
+
+            Self::deposit_event(Event::RegistryMemberRemoved(registry_id, who));
+            Ok(())
+        }
+
+        /// create claim for the invoker on a document `digest` computed with `algorithm` in a
+        /// registry whose policy allows it. The default registry is served by `create_claim`.
+        #[pallet::weight(<T as Config>::WeightInfo::create_registry_claim())]
+        pub fn create_registry_claim(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            digest: [u8; 32],
+            algorithm: HashAlgorithm,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            // Verify the policy of the registry admits the claim.
+            let registry = Registries::<T>::get(registry_id).ok_or(Error::<T>::NoSuchRegistry)?;
+            ensure!(Self::may_claim(registry_id, &registry, &sender), Error::<T>::NotAllowedToClaim);
+            let proof = Self::bounded_proof(digest.to_vec())?;
+            ensure!(proof.len() <= registry.policy.max_proof_length as usize, Error::<T>::ProofTooLong);
+
+            Self::do_create_claim(registry_id, sender, proof, Some(algorithm), None)
+        }
+
+        /// The claim owner, or the admin of the registry, revokes a claim in a registry. The
+        /// deposit is returned to the owner as for any revocation.
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_registry_claim(proof.len() as u32))]
+        pub fn revoke_registry_claim(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(registry_id != DEFAULT_REGISTRY, Error::<T>::NoSuchRegistry);
+            Self::do_revoke_claim(registry_id, sender, Self::claimed_proof(proof)?)
+        }
+
+        /// The claim owner transfers its claim in a registry to an account the policy of the
+        /// registry allows to claim, together with its deposit
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_registry_claim(proof.len() as u32))]
+        pub fn transfer_registry_claim(
+            origin: OriginFor<T>,
+            registry_id: RegistryId,
+            proof: Vec<u8>,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            let registry = Registries::<T>::get(registry_id).ok_or(Error::<T>::NoSuchRegistry)?;
+            let claim = RegistryClaims::<T>::get(registry_id, &proof).ok_or(Error::<T>::NoSuchProof)?;
+            ensure!(!Self::is_frozen(registry_id, &proof), Error::<T>::ClaimFrozen);
+            ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
+            ensure!(Self::may_claim(registry_id, &registry, &account_id), Error::<T>::NotAllowedToClaim);
+
+            Self::do_transfer_claim(registry_id, proof, claim, account_id)
+        }
+
+        /// The registry admin destroys a registry without claims or members, getting its deposit
+        /// back. The admin may revoke the claims left with `revoke_registry_claim`.
+        #[pallet::weight(<T as Config>::WeightInfo::destroy_registry())]
+        pub fn destroy_registry(origin: OriginFor<T>, registry_id: RegistryId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let registry = Registries::<T>::get(registry_id).ok_or(Error::<T>::NoSuchRegistry)?;
+            ensure!(registry.admin == sender, Error::<T>::NotRegistryAdmin);
+
+            // The claims and members go first, each by its own call, so this one stays cheap.
+            let claimed = RegistryClaims::<T>::iter_prefix_values(registry_id).next().is_some();
+            let has_members = RegistryMembers::<T>::iter_prefix_values(registry_id).next().is_some();
+            ensure!(!claimed && !has_members, Error::<T>::RegistryInUse);
+
+            Registries::<T>::remove(registry_id);
+            T::Currency::unreserve(&sender, registry.deposit);
+            Self::deposit_event(Event::DepositUnreserved(sender.clone(), registry.deposit));
+
+            Self::deposit_event(Event::RegistryDestroyed(sender, registry_id));
+            Ok(())
+        }
+
+        /// Clear the record of the revocation of `proof` in a registry once `ReclaimCooldown`
+        /// has passed, returning the deposit it kept to the former owner. Anyone may call it.
+        #[pallet::weight(<T as Config>::WeightInfo::clear_revocation())]
+        pub fn clear_revocation(origin: OriginFor<T>, registry_id: RegistryId, proof: Vec<u8>) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let proof: ProofOf<T> = proof.try_into().map_err(|_| Error::<T>::NotRevoked)?;
+            let record = Self::revocation_in(registry_id, &proof).ok_or(Error::<T>::NotRevoked)?;
+            let cooldown_end = record.block_number.saturating_add(T::ReclaimCooldown::get());
+            ensure!(frame_system::Pallet::<T>::block_number() >= cooldown_end, Error::<T>::RevokedRecently);
+            Self::remove_revocation(registry_id, &proof, record);
+
+            Self::deposit_event(Event::RevocationCleared(registry_id, proof.into()));
+            Ok(())
+        }
+
+        /// Withdraw the approval of the invoker for the open action on the jointly owned
+        /// `proof`, or object to the action if the invoker did not approve it. The action is
+        /// cancelled once no co-owner approves it, or once so many object to it that it can no
+        /// longer reach the threshold.
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_action())]
+        pub fn cancel_action(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proof = Self::claimed_proof(proof)?;
+            let (co_owners, threshold) = CoOwners::<T>::get(&proof).ok_or(Error::<T>::NoPendingAction)?;
+
+            // Verify that sender of the current call is one of the co-owners.
+            ensure!(co_owners.contains(&sender), Error::<T>::NotProofOwner);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let (action, mut approvers, mut objectors, expires_at) = Approvals::<T>::get(&proof)
+                .filter(|(_, _, _, expires_at)| now < *expires_at)
+                .ok_or(Error::<T>::NoPendingAction)?;
+
+            let cancelled = if approvers.contains(&sender) {
+                approvers.retain(|approver| approver != &sender);
+                let approved = approvers.len() as u32;
+                Self::deposit_event(Event::ApprovalWithdrawn(sender, proof.to_vec(), approved));
+                approved == 0
+            } else {
+                ensure!(!objectors.contains(&sender), Error::<T>::AlreadyObjected);
+                objectors.try_push(sender.clone()).map_err(|_| Error::<T>::TooManyCoOwners)?;
+                let objected = objectors.len() as u32;
+                Self::deposit_event(Event::ActionObjected(sender, proof.to_vec(), objected));
+                objected > (co_owners.len() as u32).saturating_sub(threshold)
+            };
+
+            if cancelled {
+                Approvals::<T>::remove(&proof);
+                Self::deposit_event(Event::ActionCancelled(proof.into()));
+            } else {
+                Approvals::<T>::insert(&proof, (action, approvers, objectors, expires_at));
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The amount to reserve for holding `proof`: a base plus a per-byte fee.
+        pub fn claim_deposit(proof: &[u8]) -> BalanceOf<T> {
+            T::ClaimDepositPerByte::get()
+                .saturating_mul((proof.len() as u32).into())
+                .saturating_add(T::ClaimDepositBase::get())
+        }
+
+        /// The payload an owner signs to have `digest` claimed on its behalf by
+        /// `create_claim_on_behalf`: the claim, tagged with `ON_BEHALF_CONTEXT` and the genesis
+        /// hash so it is only valid for this call on this chain.
+        pub fn on_behalf_payload(
+            digest: [u8; 32],
+            algorithm: HashAlgorithm,
+            deadline: T::BlockNumber,
+            nonce: u64,
+        ) -> Vec<u8> {
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+            (ON_BEHALF_CONTEXT, genesis_hash, digest, algorithm, deadline, nonce).encode()
+        }
+
+        /// The claim of `proof`, if any, as served by the runtime API.
+        pub fn claim(proof: Vec<u8>) -> Option<ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>, Vec<u8>>> {
+            let proof = Self::claimed_proof(proof).ok()?;
+            Proofs::<T>::get(proof).map(ClaimInfo::into_unbounded)
+        }
+
+        /// The claim of `proof` in a registry, if any, as served by the runtime API.
+        pub fn registry_claim(
+            registry_id: RegistryId,
+            proof: Vec<u8>,
+        ) -> Option<ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>, Vec<u8>>> {
+            if registry_id == DEFAULT_REGISTRY {
+                return Self::claim(proof)
+            }
+            let proof = Self::claimed_proof(proof).ok()?;
+            RegistryClaims::<T>::get(registry_id, proof).map(ClaimInfo::into_unbounded)
+        }
+
+        /// Whether `proof` is currently claimed.
+        pub fn is_claimed(proof: &[u8]) -> bool {
+            Self::claimed_proof(proof.to_vec()).map_or(false, |proof| Proofs::<T>::contains_key(proof))
+        }
+
+        /// Whether `proof` is claimed, revoked or has never been claimed.
+        pub fn claim_status(proof: &[u8]) -> ClaimStatus<T::AccountId, T::BlockNumber, BalanceOf<T>> {
+            let proof = match Self::claimed_proof(proof.to_vec()) {
+                Ok(proof) => proof,
+                Err(_) => return ClaimStatus::Unclaimed,
+            };
+            if Proofs::<T>::contains_key(&proof) {
+                return ClaimStatus::Claimed
+            }
+            match Revoked::<T>::get(&proof) {
+                Some(record) => ClaimStatus::Revoked(record),
+                None => ClaimStatus::Unclaimed,
+            }
+        }
+
+        /// Whether `preimage` hashes to `proof` under the algorithm recorded with its claim, so
+        /// the document behind a claim can be re-verified.
+        pub fn matches_preimage(proof: &[u8], preimage: &[u8]) -> bool {
+            let claim = Self::claimed_proof(proof.to_vec()).ok().and_then(|proof| Proofs::<T>::get(proof));
+            match claim.and_then(|claim| claim.hash_algorithm) {
+                Some(algorithm) => algorithm.hash(preimage)[..] == proof[..],
+                None => false,
+            }
+        }
+
+        /// Up to `limit` proofs claimed by `who` in the default registry, skipping the first
+        /// `start` of them. The limit is capped at `MaxClaimsPerAccount`.
+        pub fn claims_of(who: &T::AccountId, start: u32, limit: u32) -> Vec<Vec<u8>> {
+            ClaimsByOwner::<T>::iter_prefix(who)
+                .skip(start as usize)
+                .take(limit.min(T::MaxClaimsPerAccount::get()) as usize)
+                .map(|(proof, _)| proof.into())
+                .collect()
+        }
+
+        /// The claim of `proof` in a registry, if any.
+        fn claim_in(registry_id: RegistryId, proof: &ProofOf<T>) -> Option<ClaimInfoOf<T>> {
+            if registry_id == DEFAULT_REGISTRY {
+                Proofs::<T>::get(proof)
+            } else {
+                RegistryClaims::<T>::get(registry_id, proof)
+            }
+        }
+
+        /// Store `claim` of `proof` in a registry.
+        fn insert_claim(registry_id: RegistryId, proof: &ProofOf<T>, claim: ClaimInfoOf<T>) {
+            if registry_id == DEFAULT_REGISTRY {
+                Proofs::<T>::insert(proof, claim);
+            } else {
+                RegistryClaims::<T>::insert(registry_id, proof, claim);
+            }
+        }
+
+        /// Whether the claim of `proof` in a registry is frozen.
+        fn is_frozen(registry_id: RegistryId, proof: &ProofOf<T>) -> bool {
+            if registry_id == DEFAULT_REGISTRY {
+                FrozenClaims::<T>::contains_key(proof)
+            } else {
+                FrozenRegistryClaims::<T>::contains_key(registry_id, proof)
+            }
+        }
+
+        /// The revocation of the last claim of `proof` in a registry, if any.
+        fn revocation_in(
+            registry_id: RegistryId,
+            proof: &ProofOf<T>,
+        ) -> Option<RevocationRecordOf<T>> {
+            if registry_id == DEFAULT_REGISTRY {
+                Revoked::<T>::get(proof)
+            } else {
+                RevokedRegistryClaims::<T>::get(registry_id, proof)
+            }
+        }
+
+        /// Index `proof` in a registry under its new owner `who`. `MaxClaimsPerAccount` counts
+        /// the claims of `who` in all registries.
+        pub(crate) fn add_owned(registry_id: RegistryId, who: &T::AccountId, proof: &ProofOf<T>) {
+            if registry_id == DEFAULT_REGISTRY {
+                ClaimsByOwner::<T>::insert(who, proof, ());
+            } else {
+                RegistryClaimsByOwner::<T>::insert(who, (registry_id, proof), ());
+            }
+            ClaimCount::<T>::mutate(who, |count| *count = count.saturating_add(1));
+        }
+
+        /// Drop `proof` in a registry from the claims indexed under `who`.
+        pub(crate) fn remove_owned(registry_id: RegistryId, who: &T::AccountId, proof: &ProofOf<T>) {
+            if registry_id == DEFAULT_REGISTRY {
+                ClaimsByOwner::<T>::remove(who, proof);
+            } else {
+                RegistryClaimsByOwner::<T>::remove(who, (registry_id, proof));
+            }
+            ClaimCount::<T>::mutate_exists(who, |count| {
+                *count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+            });
+        }
+
+        /// Hand `claim` of `proof` in a registry over from its owner to `to`, together with its
+        /// deposit.
+        fn do_transfer_claim(
+            registry_id: RegistryId,
+            proof: ProofOf<T>,
+            mut claim: ClaimInfoOf<T>,
+            to: T::AccountId,
+        ) -> DispatchResult {
+            let from = claim.owner.clone();
+
+            // Verify the receiver can hold one more claim.
+            ensure!(Self::claim_count(&to) < T::MaxClaimsPerAccount::get(), Error::<T>::TooManyClaims);
+
+            // Hand the deposit over together with the claim.
+            let remaining = T::Currency::repatriate_reserved(
+                &from, &to, claim.deposit, BalanceStatus::Reserved
+            ).map_err(|_| Error::<T>::RepatriateFailed)?;
+            claim.deposit = claim.deposit.saturating_sub(remaining);
+            Self::deposit_event(Event::DepositRepatriated(from.clone(), to.clone(), claim.deposit));
+
+            // Overwrite claim in storage. Offers, co-owners and provenance only exist in the
+            // default registry: drop any offer for the claim, the receiver owns it alone.
+            let current_block = frame_system::Pallet::<T>::block_number();
+            claim.owner = to.clone();
+            claim.block_number = current_block;
+            Self::insert_claim(registry_id, &proof, claim);
+            T::OnClaimChange::on_transferred(registry_id, &from, &to, &proof);
+            Self::remove_owned(registry_id, &from, &proof);
+            Self::add_owned(registry_id, &to, &proof);
+            if registry_id != DEFAULT_REGISTRY {
+                Self::deposit_event(Event::RegistryClaimTransferred(from, to, registry_id, proof.into()));
+                return Ok(())
+            }
+            PendingTransfers::<T>::remove(&proof);
+            CoOwners::<T>::remove(&proof);
+            Approvals::<T>::remove(&proof);
+            Self::record_provenance(&proof, to.clone(), current_block, ClaimAction::Transferred);
+
+            // Emit an event that the claim was transferred.
+            Self::deposit_event(Event::ClaimTransferred(from, to, proof.into(), Self::now()));
+
+            Ok(())
+        }
+
+        /// The current time in milliseconds since the Unix epoch.
+        fn now() -> u64 {
+            T::UnixTime::now().as_millis().saturated_into::<u64>()
+        }
+
+        /// Verify the length of `proof` is within `ProofMinLength` and `ProofMaxLength`, so it
+        /// can be claimed.
+        pub(crate) fn bounded_proof(proof: Vec<u8>) -> Result<ProofOf<T>, DispatchError> {
+            ensure!(proof.len() >= T::ProofMinLength::get() as usize, Error::<T>::ProofTooShort);
+            proof.try_into().map_err(|_| Error::<T>::ProofTooLong.into())
+        }
+
+        /// The stored form of `proof`. Proofs longer than `ProofMaxLength` are never claimed.
+        fn claimed_proof(proof: Vec<u8>) -> Result<ProofOf<T>, Error<T>> {
+            proof.try_into().map_err(|_| Error::<T>::NoSuchProof)
+        }
+
+        /// Verify the maximum proof length of `policy` is within `ProofMinLength` and
+        /// `ProofMaxLength`.
+        fn ensure_policy_valid(policy: &RegistryPolicy) -> DispatchResult {
+            let max_proof_length = policy.max_proof_length;
+            ensure!(
+                max_proof_length >= T::ProofMinLength::get() && max_proof_length <= T::ProofMaxLength::get(),
+                Error::<T>::InvalidPolicy
+            );
+            Ok(())
+        }
+
+        /// Verify `who` administers the registry.
+        fn ensure_registry_admin(registry_id: RegistryId, who: &T::AccountId) -> DispatchResult {
+            let registry = Registries::<T>::get(registry_id).ok_or(Error::<T>::NoSuchRegistry)?;
+            ensure!(&registry.admin == who, Error::<T>::NotRegistryAdmin);
+            Ok(())
+        }
+
+        /// Whether the policy of `registry` lets `who` claim in it.
+        fn may_claim(
+            registry_id: RegistryId,
+            registry: &RegistryInfo<T::AccountId, BalanceOf<T>>,
+            who: &T::AccountId,
+        ) -> bool {
+            match registry.policy.claimers {
+                ClaimPolicy::Anyone => true,
+                ClaimPolicy::Members =>
+                    &registry.admin == who || RegistryMembers::<T>::contains_key(registry_id, who),
+            }
+        }
+
+        /// The first block from `at` on at which fewer than `MaxExpiriesPerBlock` claims expire.
+        fn expiry_slot(mut at: T::BlockNumber) -> T::BlockNumber {
+            let max = T::MaxExpiriesPerBlock::get() as usize;
+            while ClaimExpiries::<T>::decode_len(at).unwrap_or_default() >= max {
+                at = at.saturating_add(One::one());
+            }
+            at
+        }
+
+        /// Claim `proof` in a registry for `sender`, see `create_claim`. The caller checks the
+        /// policy of the registry.
+        pub(crate) fn do_create_claim(
+            registry_id: RegistryId,
+            sender: T::AccountId,
+            proof: ProofOf<T>,
+            hash_algorithm: Option<HashAlgorithm>,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            // Verify that the specified proof has not already been claimed in the registry.
+            ensure!(Self::claim_in(registry_id, &proof).is_none(), Error::<T>::ProofAlreadyClaimed);
+
+            // Get the block number from the FRAME System module.
+            let current_block = <frame_system::Pallet<T>>::block_number();
+
+            // Verify the proof was not revoked too recently to be claimed by someone else.
+            let revoked = Self::revocation_in(registry_id, &proof);
+            if let Some(revoked) = &revoked {
+                let cooldown_end = revoked.block_number.saturating_add(T::ReclaimCooldown::get());
+                ensure!(current_block >= cooldown_end, Error::<T>::RevokedRecently);
+            }
+
+            // Verify the claim does not expire before it exists, and find a block to remove it at.
+            if let Some(at) = expires_at {
+                ensure!(at > current_block, Error::<T>::InvalidExpiry);
+            }
+            let expires_at = expires_at.map(Self::expiry_slot);
+
+            // Verify the sender can hold one more claim.
+            ensure!(Self::claim_count(&sender) < T::MaxClaimsPerAccount::get(), Error::<T>::TooManyClaims);
+
+            // Reserve the deposit for holding the claim.
+            let deposit = Self::claim_deposit(&proof);
+            T::Currency::reserve(&sender, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalanceToReserve)?;
+            Self::deposit_event(Event::DepositReserved(sender.clone(), deposit));
+
+            // Store the proof with the sender, block number and time.
+            let timestamp = Self::now();
+            Self::insert_claim(registry_id, &proof, ClaimInfo {
+                owner: sender.clone(),
+                created_at: current_block,
+                timestamp,
+                block_number: current_block,
+                deposit,
+                hash_algorithm,
+                expires_at,
+                description: Default::default(),
+                content_type: Default::default(),
+                uri: Default::default(),
+            });
+            if let Some(revoked) = revoked {
+                Self::remove_revocation(registry_id, &proof, revoked);
+            }
+            Self::add_owned(registry_id, &sender, &proof);
+            T::OnClaimChange::on_created(registry_id, &sender, &proof);
+            if registry_id != DEFAULT_REGISTRY {
+                Self::deposit_event(Event::RegistryClaimCreated(sender, registry_id, proof.into(), timestamp));
+                return Ok(())
+            }
+            Self::record_provenance(&proof, sender.clone(), current_block, ClaimAction::Created);
+
+            // Queue the claim for removal at its expiry block.
+            if let Some(at) = expires_at {
+                let _ = ClaimExpiries::<T>::try_append(at, &proof);
+            }
+
+            // Emit an event that the claim was created.
+            Self::deposit_event(Event::ClaimCreated(sender, proof.into(), timestamp));
+            Ok(())
+        }
+
+        /// Revoke `proof` in a registry on behalf of `sender`, see `revoke_claim`. Delegates and
+        /// co-owners only act on claims in the default registry.
+        fn do_revoke_claim(registry_id: RegistryId, sender: T::AccountId, proof: ProofOf<T>) -> DispatchResult {
+            // Verify that the specified proof has been claimed and get its owner.
+            let claim = Self::claim_in(registry_id, &proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Verify the claim is not disputed.
+            ensure!(!Self::is_frozen(registry_id, &proof), Error::<T>::ClaimFrozen);
+
+            if registry_id != DEFAULT_REGISTRY {
+                // The admin may revoke any claim, so no member can keep the registry in use.
+                ensure!(
+                    sender == claim.owner ||
+                        Registries::<T>::get(registry_id).map_or(false, |registry| registry.admin == sender),
+                    Error::<T>::NotProofOwner
+                );
+                Self::remove_claim(registry_id, &proof, &claim, ClaimAction::Revoked);
+                Self::record_revocation(registry_id, &proof, &claim, Some(sender.clone()));
+                Self::deposit_event(Event::RegistryClaimRevoked(sender, registry_id, proof.into()));
+                return Ok(())
+            }
+
+            // An authorised delegate acts for the owner.
+            let sender = Self::acting_for(sender, &claim.owner, &proof, ClaimPermission::Revoke);
+
+            if CoOwners::<T>::contains_key(&proof) {
+                // Wait for the approval of enough co-owners.
+                if !Self::approve(&sender, &proof, &JointAction::Revoke)? {
+                    return Ok(())
+                }
+            } else {
+                // Verify that sender of the current call is the claim owner.
+                ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
+            }
+
+            // Remove claim from storage and return the deposit to the owner.
+            Self::remove_claim(registry_id, &proof, &claim, ClaimAction::Revoked);
+            Self::record_revocation(registry_id, &proof, &claim, Some(sender.clone()));
+
+            // Emit an event that the claim was erased.
+            Self::deposit_event(Event::ClaimRevoked(sender, proof.into()));
+            Ok(())
+        }
+
+        /// Whether `delegate` may currently act for `owner` with `permission`.
+        pub fn is_delegate(owner: &T::AccountId, delegate: &T::AccountId, permission: ClaimPermission) -> bool {
+            match Delegates::<T>::get(owner, delegate) {
+                Some((permissions, expires_at)) => permissions.allows(permission) &&
+                    expires_at.map_or(true, |at| frame_system::Pallet::<T>::block_number() < at),
+                None => false,
+            }
+        }
+
+        /// The account `sender` acts as on the claim of `proof` held by `owner`: the owner when
+        /// `sender` is a delegate with `permission`, or `sender` itself.
+        fn acting_for(
+            sender: T::AccountId,
+            owner: &T::AccountId,
+            proof: &ProofOf<T>,
+            permission: ClaimPermission,
+        ) -> T::AccountId {
+            if sender == *owner || !Self::is_delegate(owner, &sender, permission) {
+                return sender
+            }
+
+            Self::deposit_event(Event::DelegateActed(sender, owner.clone(), proof.to_vec()));
+            owner.clone()
+        }
+
+        /// Record the approval of co-owner `who` for `action` on the jointly owned `proof`,
+        /// returning whether enough co-owners approved it for the action to go through.
+        /// Approvals older than `ApprovalPeriod` are discarded. Until then, or until the action is
+        /// cancelled with `cancel_action`, the co-owners can't start approving another action.
+        fn approve(
+            who: &T::AccountId,
+            proof: &ProofOf<T>,
+            action: &JointAction<T::AccountId>,
+        ) -> Result<bool, DispatchError> {
+            let (co_owners, threshold) = CoOwners::<T>::get(proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Verify that sender of the current call is one of the co-owners.
+            ensure!(co_owners.contains(who), Error::<T>::NotProofOwner);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let (mut approvers, mut objectors, expires_at) = match Approvals::<T>::get(proof) {
+                Some((open, approvers, objectors, expires_at)) if now < expires_at => {
+                    ensure!(open == *action, Error::<T>::ActionPending);
+                    (approvers, objectors, expires_at)
+                },
+                _ => (Default::default(), Default::default(), now.saturating_add(T::ApprovalPeriod::get())),
+            };
+            ensure!(!approvers.contains(who), Error::<T>::AlreadyApproved);
+            approvers.try_push(who.clone()).map_err(|_| Error::<T>::TooManyCoOwners)?;
+            // A co-owner who approves drops its objection.
+            objectors.retain(|objector| objector != who);
+
+            let approved = approvers.len() as u32;
+            Approvals::<T>::insert(proof, (action.clone(), approvers, objectors, expires_at));
+
+            Self::deposit_event(Event::ActionApproved(who.clone(), proof.to_vec(), approved));
+            Ok(approved >= threshold)
+        }
+
+        /// Append an entry to the provenance log of `proof`, dropping its oldest entry if the
+        /// log is full.
+        pub(crate) fn record_provenance(
+            proof: &ProofOf<T>,
+            owner: T::AccountId,
+            block_number: T::BlockNumber,
+            action: ClaimAction,
+        ) {
+            ClaimHistory::<T>::mutate(proof, |history| {
+                if !history.is_empty() && history.len() >= T::MaxHistoryLength::get() as usize {
+                    history.remove(0);
+                }
+                let _ = history.try_push(ProvenanceRecord { owner, block_number, action });
+            });
+        }
+
+        /// The part of the deposit of `claim` kept reserved while the record of its revocation
+        /// exists, so the record is not stored for free.
+        fn revocation_deposit(claim: &ClaimInfoOf<T>) -> BalanceOf<T> {
+            claim.deposit.min(T::RevocationDeposit::get())
+        }
+
+        /// Keep a record of the revocation of `claim` of `proof` in a registry, so it can't be
+        /// claimed again right away. The record holds on to part of the deposit until it is
+        /// cleared, by `clear_revocation` or by claiming the proof again.
+        fn record_revocation(
+            registry_id: RegistryId,
+            proof: &ProofOf<T>,
+            claim: &ClaimInfoOf<T>,
+            revoker: Option<T::AccountId>,
+        ) {
+            let record = RevocationRecord {
+                owner: claim.owner.clone(),
+                revoker,
+                block_number: frame_system::Pallet::<T>::block_number(),
+                deposit: Self::revocation_deposit(claim),
+            };
+            if registry_id == DEFAULT_REGISTRY {
+                Revoked::<T>::insert(proof, record);
+            } else {
+                RevokedRegistryClaims::<T>::insert(registry_id, proof, record);
+            }
+        }
+
+        /// Drop the `record` of the revocation of `proof` in a registry, returning the deposit
+        /// it kept to the former owner.
+        fn remove_revocation(registry_id: RegistryId, proof: &ProofOf<T>, record: RevocationRecordOf<T>) {
+            if registry_id == DEFAULT_REGISTRY {
+                Revoked::<T>::remove(proof);
+            } else {
+                RevokedRegistryClaims::<T>::remove(registry_id, proof);
+            }
+            if !record.deposit.is_zero() {
+                T::Currency::unreserve(&record.owner, record.deposit);
+                Self::deposit_event(Event::DepositUnreserved(record.owner, record.deposit));
+            }
+        }
+
+        /// Drop `proof` in a registry and everything attached to it but its provenance log,
+        /// which records how the claim ended, and return the deposit to its owner. A revoked
+        /// claim leaves the revocation deposit reserved, see `record_revocation`.
+        fn remove_claim(registry_id: RegistryId, proof: &ProofOf<T>, claim: &ClaimInfoOf<T>, action: ClaimAction) {
+            Self::remove_owned(registry_id, &claim.owner, proof);
+            T::OnClaimChange::on_revoked(registry_id, &claim.owner, proof);
+            let kept = match action {
+                ClaimAction::Revoked => Self::revocation_deposit(claim),
+                _ => Zero::zero(),
+            };
+            let refund = claim.deposit.saturating_sub(kept);
+            T::Currency::unreserve(&claim.owner, refund);
+            Self::deposit_event(Event::DepositUnreserved(claim.owner.clone(), refund));
+            if registry_id != DEFAULT_REGISTRY {
+                RegistryClaims::<T>::remove(registry_id, proof);
+                FrozenRegistryClaims::<T>::remove(registry_id, proof);
+                return
+            }
+
+            Proofs::<T>::remove(proof);
+            MerkleRoots::<T>::remove(proof);
+            PendingTransfers::<T>::remove(proof);
+            let now = frame_system::Pallet::<T>::block_number();
+            Self::record_provenance(proof, claim.owner.clone(), now, action);
+            CoOwners::<T>::remove(proof);
+            Approvals::<T>::remove(proof);
+            FrozenClaims::<T>::remove(proof);
+
+            // Free the slot of the claim in the queue of its expiry block.
+            if let Some(at) = claim.expires_at {
+                ClaimExpiries::<T>::mutate_exists(at, |expiring| {
+                    if let Some(proofs) = expiring {
+                        proofs.retain(|queued| queued != proof);
+                        if proofs.is_empty() {
+                            *expiring = None;
+                        }
+                    }
+                });
+            }
+        }
+
+        /// Whether `leaf` belongs to the anchored Merkle `root`, given the sibling hashes on the
+        /// path from the leaf up to the root. Pairs are hashed in sorted order, so the path needs
+        /// no left or right markers. Leaves and inner nodes are hashed with different prefixes,
+        /// so neither the root nor any other inner node passes for a leaf.
+        pub fn verify_inclusion(root: [u8; 32], leaf: [u8; 32], proof_path: Vec<[u8; 32]>) -> bool {
+            let root_proof = match Self::claimed_proof(root.to_vec()) {
+                Ok(proof) => proof,
+                Err(_) => return false,
+            };
+            let leaf_count = match MerkleRoots::<T>::get(root_proof) {
+                Some(leaf_count) => leaf_count,
+                None => return false,
+            };
+
+            // A tree of `leaf_count` leaves is at most ceil(log2(leaf_count)) levels deep.
+            let depth = 32 - leaf_count.saturating_sub(1).leading_zeros();
+            if proof_path.len() > depth as usize {
+                return false
+            }
+
+            let leaf = Self::hash_leaf(&leaf);
+            proof_path.iter().fold(leaf, |node, sibling| Self::hash_pair(&node, sibling)) == root
+        }
+
+        /// The Merkle tree node of the document hash `leaf`.
+        pub fn hash_leaf(leaf: &[u8; 32]) -> [u8; 32] {
+            let mut node = [0u8; 33];
+            node[0] = MERKLE_LEAF_PREFIX;
+            node[1..].copy_from_slice(leaf);
+            blake2_256(&node)
+        }
+
+        /// The parent of two Merkle tree nodes.
+        pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+            let (left, right) = if a <= b { (a, b) } else { (b, a) };
+            let mut pair = [0u8; 65];
+            pair[0] = MERKLE_NODE_PREFIX;
+            pair[1..33].copy_from_slice(left);
+            pair[33..].copy_from_slice(right);
+            blake2_256(&pair)
+        }
+
+        /// Remove `proof` if it is still claimed and due to expire by block `now`. Revoked claims
+        /// leave the `ClaimExpiries` queue, but entries of claims that are gone are skipped all
+        /// the same.
+        fn expire_claim(now: T::BlockNumber, proof: ProofOf<T>) {
+            let claim = match Proofs::<T>::get(&proof) {
+                Some(claim) if claim.expires_at.map_or(false, |at| at <= now) => claim,
+                _ => return,
+            };
+
+            Self::remove_claim(DEFAULT_REGISTRY, &proof, &claim, ClaimAction::Expired);
+            Self::deposit_event(Event::ClaimExpired(claim.owner, proof.into()));
+        }
     }
 }
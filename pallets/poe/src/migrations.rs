@@ -0,0 +1,50 @@
+//! Storage migrations for pallet-poe.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Zero;
+
+/// Version 1 turns every `(owner, block_number)` tuple in `Proofs` into a `ClaimInfo`.
+pub mod v1 {
+	use super::*;
+
+	/// Migrate `Proofs` from `(T::AccountId, T::BlockNumber)` to `ClaimInfo`. Claims that
+	/// predate the migration come out with empty metadata, no deposit, no timestamp and no known
+	/// hash algorithm, are indexed in `ClaimsByOwner` and start their provenance log at their
+	/// last recorded block.
+	///
+	/// The tuples are translated straight into the current layout.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated = 0u64;
+		Proofs::<T>::translate::<(T::AccountId, T::BlockNumber), _>(
+			|proof, (owner, block_number)| {
+				translated += 1;
+				Pallet::<T>::add_owned(DEFAULT_REGISTRY, &owner, &proof);
+				Pallet::<T>::record_provenance(&proof, owner.clone(), block_number, ClaimAction::Created);
+				Some(ClaimInfo {
+					owner,
+					created_at: block_number,
+					timestamp: Zero::zero(),
+					block_number,
+					deposit: Zero::zero(),
+					hash_algorithm: None,
+					expires_at: None,
+					description: Default::default(),
+					content_type: Default::default(),
+					uri: Default::default(),
+				})
+			},
+		);
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(3 * translated + 1, 4 * translated + 1)
+	}
+}
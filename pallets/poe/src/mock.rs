@@ -1,11 +1,13 @@
 use crate as pallet_poe;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::Get};
 use frame_system as system;
+use pallet_poe::{OnClaimChange, RegistryId};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -18,15 +20,18 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		PoeModule: pallet_poe::{Pallet, Call, Config<T>, Storage, Event<T>},
 	}
 );
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
-	pub const ProofMaxLength: usize = 32;
-	pub const ProofMinLength: usize = 4;
+	pub const ProofMaxLength: u32 = 32;
+	pub const ProofMinLength: u32 = 4;
+	pub const MaxMetadataLength: u32 = 16;
 }
 
 impl system::Config for Test {
@@ -47,7 +52,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -55,13 +60,148 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+thread_local! {
+	static EXISTENTIAL_DEPOSIT: RefCell<u64> = RefCell::new(1);
+}
+
+/// The existential deposit of balances, 1 unless set by `set_existential_deposit`.
+pub struct ExistentialDeposit;
+
+impl Get<u64> for ExistentialDeposit {
+	fn get() -> u64 {
+		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
+	}
+}
+
+/// Set the existential deposit of balances for the rest of the test.
+pub fn set_existential_deposit(amount: u64) {
+	EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = amount);
+}
+
+parameter_types! {
+	pub const MaxReserves: u32 = 50;
+	pub const ClaimDepositBase: u64 = 10;
+	pub const ClaimDepositPerByte: u64 = 1;
+	pub const MaxExpiriesPerBlock: u32 = 2;
+	pub const MaxClaimsPerAccount: u32 = 4;
+	pub const MaxBatchSize: u32 = 3;
+	pub const OfferDuration: u64 = 5;
+	pub const MaxHistoryLength: u32 = 3;
+	pub const MaxCoOwners: u32 = 3;
+	pub const ApprovalPeriod: u64 = 5;
+	pub const ReclaimCooldown: u64 = 3;
+	pub const RevocationDeposit: u64 = 5;
+	pub const MaxContentLength: u32 = 64;
+	pub const RegistryDeposit: u64 = 100;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
 impl pallet_poe::Config for Test {
 	type Event = Event;
 	type ProofMaxLength = ProofMaxLength;
 	type ProofMinLength = ProofMinLength;
+	type MaxMetadataLength = MaxMetadataLength;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type MaxBatchSize = MaxBatchSize;
+	type OfferDuration = OfferDuration;
+	type MaxHistoryLength = MaxHistoryLength;
+	type MaxCoOwners = MaxCoOwners;
+	type ApprovalPeriod = ApprovalPeriod;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SignAsTestKey;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type UnixTime = Timestamp;
+	type ReclaimCooldown = ReclaimCooldown;
+	type RevocationDeposit = RevocationDeposit;
+	type MaxContentLength = MaxContentLength;
+	type OnClaimChange = (RecordClaimChanges, ());
+	type RegistryDeposit = RegistryDeposit;
+	type WeightInfo = ();
+}
+
+/// A change of a claim, as seen by an `OnClaimChange` listener.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClaimChange {
+	Created(RegistryId, u64, Vec<u8>),
+	Transferred(RegistryId, u64, u64, Vec<u8>),
+	Revoked(RegistryId, u64, Vec<u8>),
+}
+
+thread_local! {
+	static CLAIM_CHANGES: RefCell<Vec<ClaimChange>> = RefCell::new(vec![]);
+}
+
+/// Records every claim change, see `claim_changes`.
+pub struct RecordClaimChanges;
+
+impl OnClaimChange<u64> for RecordClaimChanges {
+	fn on_created(registry_id: RegistryId, owner: &u64, proof: &[u8]) {
+		let change = ClaimChange::Created(registry_id, *owner, proof.to_vec());
+		CLAIM_CHANGES.with(|c| c.borrow_mut().push(change));
+	}
+
+	fn on_transferred(registry_id: RegistryId, from: &u64, to: &u64, proof: &[u8]) {
+		let change = ClaimChange::Transferred(registry_id, *from, *to, proof.to_vec());
+		CLAIM_CHANGES.with(|c| c.borrow_mut().push(change));
+	}
+
+	fn on_revoked(registry_id: RegistryId, owner: &u64, proof: &[u8]) {
+		let change = ClaimChange::Revoked(registry_id, *owner, proof.to_vec());
+		CLAIM_CHANGES.with(|c| c.borrow_mut().push(change));
+	}
+}
+
+/// The claim changes recorded so far.
+pub fn claim_changes() -> Vec<ClaimChange> {
+	CLAIM_CHANGES.with(|c| c.borrow().clone())
+}
+
+/// Signs benchmark payloads as account 100, which holds no funds.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SignAsTestKey;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<TestSignature, u64> for SignAsTestKey {
+	fn sign(payload: &[u8]) -> (u64, TestSignature) {
+		(100, TestSignature(100, payload.to_vec()))
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1000), (2, 1000), (3, 1000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	t.into()
 }
@@ -1,8 +1,12 @@
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::{GenesisBuild, Hooks, StorageVersion}};
 use super::*;
 use crate::mock::Event;
 use frame_system as system;
+use sp_runtime::testing::TestSignature;
+use codec::Encode;
+use sp_core::H256;
+use sp_std::convert::TryInto;
 
 /// helper functions to retrieve events emited by extrinsics
 /// reference: https://github.com/paritytech/substrate/blob/83942f58fc859ef5790351691e1ef665d79f0ead/frame/balances/src/tests.rs#L470-L473
@@ -10,25 +14,43 @@ fn last_event() -> Event {
 	system::Pallet::<Test>::events().pop().expect("Event expected").event
 }
 
+/// the stored form of `proof`
+fn proof_of(proof: &[u8]) -> ProofOf<Test> {
+	proof.to_vec().try_into().unwrap()
+}
+
 
 #[test]
 fn create_valid_claim_should_work() {
 	new_test_ext().execute_with(|| {
-		let claim = b"claim".to_vec();
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
 		let sender = 1;
 		// !IMPORTANT block number is 0 by default, events only emit when block number is greater than 0
 		System::set_block_number(1);
+		Timestamp::set_timestamp(1_633_000_000_000);
 		// postive case
-		assert_ok!(PoeModule::create_claim(Origin::signed(sender), claim.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
 		// verify storage
 		assert_eq!(
-			Proofs::<Test>::get(&claim),
-			(1, system::Pallet::<Test>::block_number())
+			Proofs::<Test>::get(&proof_of(&claim)),
+			Some(ClaimInfo {
+				owner: 1,
+				created_at: system::Pallet::<Test>::block_number(),
+				timestamp: 1_633_000_000_000,
+				block_number: system::Pallet::<Test>::block_number(),
+				deposit: 42,
+				hash_algorithm: Some(HashAlgorithm::Blake2_256),
+				expires_at: None,
+				description: Default::default(),
+				content_type: Default::default(),
+				uri: Default::default(),
+			})
 		);
 		// capture events and compare
 		assert_eq!(
 			last_event(),
-			Event::PoeModule(crate::Event::ClaimCreated(sender, claim))
+			Event::PoeModule(crate::Event::ClaimCreated(sender, claim, 1_633_000_000_000))
 		)
 	});
 }
@@ -36,24 +58,17 @@ fn create_valid_claim_should_work() {
 #[test]
 fn create_invalid_claim_should_fail() {
 	new_test_ext().execute_with(|| {
-		let short_claim = b"clm".to_vec();
-		let long_claim = short_claim.repeat(12);
-		let valid_claim = short_claim.repeat(3);
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
 		let sender = 1;
-		// negative case for claim length validation
-		assert_noop!(
-			PoeModule::create_claim(Origin::signed(sender), short_claim),
-			Error::<Test>::ProofTooShort
-		);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		// negative case for repeat creation
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(sender), long_claim),
-			Error::<Test>::ProofTooLong
+			PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None),
+			Error::<Test>::ProofAlreadyClaimed
 		);
-
-		assert_ok!(PoeModule::create_claim(Origin::signed(sender), valid_claim.clone()));
-		// negative case for repeat creation
+		// the same digest can not be claimed again under another algorithm
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(sender), valid_claim),
+			PoeModule::create_claim(Origin::signed(2), digest, HashAlgorithm::Sha2_256, None),
 			Error::<Test>::ProofAlreadyClaimed
 		);
 	});
@@ -62,19 +77,17 @@ fn create_invalid_claim_should_fail() {
 #[test]
 fn revoke_claim_should_work() {
 	new_test_ext().execute_with(|| {
-		let claim = b"claim".to_vec();
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
 		let sender = 1;
 		// !IMPORTANT block number is 0 by default, events only emit when block number is greater than 0
 		System::set_block_number(1);
 		// postive case
-		assert_ok!(PoeModule::create_claim(Origin::signed(sender), claim.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
 		assert_ok!(PoeModule::revoke_claim(Origin::signed(sender), claim.clone()));
 
 		// verify storage
-		assert_ne!(
-			Proofs::<Test>::get(&claim),
-			(sender, system::Pallet::<Test>::block_number())
-		);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)), None);
 
 		// capture events and compare
 		assert_eq!(
@@ -87,11 +100,12 @@ fn revoke_claim_should_work() {
 #[test]
 fn revoke_false_claim_should_fail() {
 	new_test_ext().execute_with(|| {
-		let claim = b"claim".to_vec();
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
 		let sender = 1;
 		let hacker = 2; 
 		// postive case
-		assert_ok!(PoeModule::create_claim(Origin::signed(sender), claim.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
 		// not owner can not revoke
 		assert_noop!(
 			PoeModule::revoke_claim(Origin::signed(hacker), claim.clone()),
@@ -100,7 +114,7 @@ fn revoke_false_claim_should_fail() {
 
 		// can not revoke non-existent claim
 		assert_noop!(
-			PoeModule::revoke_claim(Origin::signed(sender), claim.repeat(2)),
+			PoeModule::revoke_claim(Origin::signed(sender), vec![0; 32]),
 			Error::<Test>::NoSuchProof
 		);
 	});
@@ -109,25 +123,25 @@ fn revoke_false_claim_should_fail() {
 #[test]
 fn transfer_claim_should_work() {
 	new_test_ext().execute_with(|| {
-		let claim = b"claim".to_vec();
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
 		let sender = 1;
 		let receiver = 2;
 		// !IMPORTANT block number is 0 by default, events only emit when block number is greater than 0
 		System::set_block_number(1);
 		// postive case
-		assert_ok!(PoeModule::create_claim(Origin::signed(sender), claim.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
 		assert_ok!(PoeModule::transfer_claim(Origin::signed(sender), claim.clone(), receiver));
 
 		// verify storage
-		assert_eq!(
-			Proofs::<Test>::get(&claim),
-			(receiver, system::Pallet::<Test>::block_number())
-		);
+		let info = Proofs::<Test>::get(&proof_of(&claim)).unwrap();
+		assert_eq!(info.owner, receiver);
+		assert_eq!(info.block_number, system::Pallet::<Test>::block_number());
 
 		// capture events and compare
 		assert_eq!(
 			last_event(),
-			Event::PoeModule(crate::Event::ClaimTransferred(sender, receiver,claim))
+			Event::PoeModule(crate::Event::ClaimTransferred(sender, receiver, claim, 0))
 		)
 	});
 }
@@ -135,12 +149,13 @@ fn transfer_claim_should_work() {
 #[test]
 fn transfer_claim_should_fail() {
 	new_test_ext().execute_with(|| {
-		let claim = b"claim".to_vec();
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
 		let sender = 1;
 		let receiver = 2; 
 		let hacker = 3;
 		// postive case
-		assert_ok!(PoeModule::create_claim(Origin::signed(sender), claim.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
 		// not owner can not transfer
 		assert_noop!(
 			PoeModule::transfer_claim(Origin::signed(hacker), claim.clone(), receiver),
@@ -149,8 +164,1590 @@ fn transfer_claim_should_fail() {
 
 		// can not transfer non-existent claim
 		assert_noop!(
-			PoeModule::transfer_claim(Origin::signed(sender), claim.repeat(2), receiver),
+			PoeModule::transfer_claim(Origin::signed(sender), vec![0; 32], receiver),
+			Error::<Test>::NoSuchProof
+		);
+	});
+}
+
+#[test]
+fn set_claim_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::set_claim_metadata(
+			Origin::signed(sender),
+			claim.clone(),
+			b"contract".to_vec(),
+			b"application/pdf".to_vec(),
+			b"ipfs://Qm".to_vec(),
+		));
+
+		// verify storage
+		let info = Proofs::<Test>::get(&proof_of(&claim)).unwrap();
+		assert_eq!(info.description.to_vec(), b"contract".to_vec());
+		assert_eq!(info.content_type.to_vec(), b"application/pdf".to_vec());
+		assert_eq!(info.uri.to_vec(), b"ipfs://Qm".to_vec());
+
+		// capture events and compare
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimMetadataSet(sender, claim))
+		)
+	});
+}
+
+#[test]
+fn set_claim_metadata_should_fail() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let hacker = 2;
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		// not owner can not describe the claim
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(hacker), claim.clone(), vec![], vec![], vec![]),
+			Error::<Test>::NotProofOwner
+		);
+		// can not describe non-existent claim
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(sender), vec![0; 32], vec![], vec![], vec![]),
 			Error::<Test>::NoSuchProof
 		);
+		// every field is bounded by `MaxMetadataLength`
+		assert_noop!(
+			PoeModule::set_claim_metadata(Origin::signed(sender), claim, vec![0; 17], vec![], vec![]),
+			Error::<Test>::MetadataTooLong
+		);
+	});
+}
+
+#[test]
+fn migrate_legacy_proofs_should_work() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		// write a claim in the pre-`ClaimInfo` layout
+		frame_support::storage::unhashed::put(
+			&Proofs::<Test>::hashed_key_for(&proof_of(&claim)),
+			&(1u64, 5u64),
+		);
+
+		migrations::v1::migrate::<Test>();
+
+		let info = Proofs::<Test>::get(&proof_of(&claim)).unwrap();
+		assert_eq!((info.owner, info.block_number), (1, 5));
+		assert_eq!(info.deposit, 0);
+		assert!(info.description.is_empty());
+		assert_eq!(info.created_at, 5);
+		assert!(ClaimsByOwner::<Test>::contains_key(1, &proof_of(&claim)));
+		assert_eq!(PoeModule::claim_history(&proof_of(&claim)).len(), 1);
+		assert_eq!(info.timestamp, 0);
+		assert_eq!(StorageVersion::get::<PoeModule>(), 1);
+	});
+}
+
+#[test]
+fn claim_deposit_should_follow_the_claim() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let receiver = 2;
+		System::set_block_number(1);
+		// base deposit of 10 plus 1 per byte
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_eq!(Balances::reserved_balance(sender), 42);
+
+		// the deposit moves with the claim
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(sender), claim.clone(), receiver));
+		assert_eq!(Balances::reserved_balance(sender), 0);
+		assert_eq!(Balances::free_balance(sender), 958);
+		assert_eq!(Balances::reserved_balance(receiver), 42);
+
+		// and is returned to the current owner on revoke, but for the revocation deposit kept
+		// with the record of the revocation
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(receiver), claim.clone()));
+		assert_eq!(Balances::reserved_balance(receiver), 5);
+		assert_eq!(Balances::free_balance(receiver), 1037);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::PoeModule(crate::Event::DepositUnreserved(receiver, 37))));
+
+		// which comes back once the record is cleared after the cooldown
+		System::set_block_number(4);
+		assert_ok!(PoeModule::clear_revocation(Origin::signed(sender), DEFAULT_REGISTRY, claim));
+		assert_eq!(Balances::reserved_balance(receiver), 0);
+		assert_eq!(Balances::free_balance(receiver), 1042);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::RevocationCleared(DEFAULT_REGISTRY, digest.to_vec()))
+		);
+	});
+}
+
+#[test]
+fn claim_deposit_should_fail() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let poor = 9;
+		let sender = 1;
+		let nobody = 8;
+		// can not claim without funds for the deposit
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(poor), digest, HashAlgorithm::Blake2_256, None),
+			Error::<Test>::InsufficientBalanceToReserve
+		);
+
+		// the deposit can not move to an account that does not exist
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(sender), claim, nobody),
+			Error::<Test>::RepatriateFailed
+		);
+	});
+}
+
+#[test]
+fn expired_claims_should_be_removed() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		System::set_block_number(1);
+		let claims: Vec<Vec<u8>> = (0u8..3).map(|i| vec![i; 32]).collect();
+		for i in 0u8..2 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(sender), [i; 32], HashAlgorithm::Blake2_256, Some(3)));
+		}
+		// a claim over `MaxExpiriesPerBlock` expires at the next block with room for it
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), [2; 32], HashAlgorithm::Blake2_256, Some(3)));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claims[2])).unwrap().expires_at, Some(4));
+		assert_eq!(ClaimExpiries::<Test>::get(4).to_vec(), vec![proof_of(&claims[2])]);
+		assert_eq!(Balances::reserved_balance(sender), 126);
+
+		// nothing is due yet
+		PoeModule::on_initialize(2);
+		assert!(Proofs::<Test>::contains_key(&proof_of(&claims[0])));
+
+		System::set_block_number(3);
+		PoeModule::on_initialize(3);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claims[0])), None);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claims[1])), None);
+		assert!(Proofs::<Test>::contains_key(&proof_of(&claims[2])));
+		assert!(!ClaimExpiries::<Test>::contains_key(3));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimExpired(sender, claims[1].clone()))
+		);
+		assert_eq!(
+			PoeModule::claim_history(&proof_of(&claims[0])).last(),
+			Some(&ProvenanceRecord { owner: sender, block_number: 3, action: ClaimAction::Expired })
+		);
+
+		System::set_block_number(4);
+		PoeModule::on_initialize(4);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claims[2])), None);
+		assert!(!ClaimExpiries::<Test>::contains_key(4));
+		// every deposit has been returned
+		assert_eq!(Balances::reserved_balance(sender), 0);
+	});
+}
+
+#[test]
+fn revoked_claims_should_not_expire_again() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let other = 2;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, Some(5)));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(sender), claim.clone()));
+		// the revoked claim left the queue of its expiry block
+		assert!(!ClaimExpiries::<Test>::contains_key(5));
+		// the same proof claimed again without expiry survives its old expiry block
+		System::set_block_number(4);
+		assert_ok!(PoeModule::create_claim(Origin::signed(other), digest, HashAlgorithm::Blake2_256, None));
+
+		System::set_block_number(5);
+		PoeModule::on_initialize(5);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, other);
+	});
+}
+
+#[test]
+fn revoked_claims_should_free_their_expiry_slot() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		System::set_block_number(1);
+		for i in 0u8..2 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(sender), [i; 32], HashAlgorithm::Blake2_256, Some(3)));
+		}
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(sender), vec![0; 32]));
+		assert_eq!(ClaimExpiries::<Test>::get(3).to_vec(), vec![proof_of(&[1; 32])]);
+
+		// the slot is free for another claim, which expires as queued
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), [2; 32], HashAlgorithm::Blake2_256, Some(3)));
+		System::set_block_number(3);
+		PoeModule::on_initialize(3);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&[1; 32])), None);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&[2; 32])), None);
+	});
+}
+
+#[test]
+fn create_claim_with_past_expiry_should_fail() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), [0; 32], HashAlgorithm::Blake2_256, Some(5)),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn claims_by_owner_should_stay_in_sync() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let receiver = 2;
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert!(ClaimsByOwner::<Test>::contains_key(sender, &proof_of(&claim)));
+		assert_eq!(PoeModule::claim_count(sender), 1);
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(sender), claim.clone(), receiver));
+		assert!(!ClaimsByOwner::<Test>::contains_key(sender, &proof_of(&claim)));
+		assert!(ClaimsByOwner::<Test>::contains_key(receiver, &proof_of(&claim)));
+		assert_eq!(PoeModule::claim_count(sender), 0);
+		assert_eq!(PoeModule::claim_count(receiver), 1);
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(receiver), claim.clone()));
+		assert!(!ClaimsByOwner::<Test>::contains_key(receiver, &proof_of(&claim)));
+		assert!(!ClaimCount::<Test>::contains_key(receiver));
+	});
+}
+
+#[test]
+fn claims_of_should_paginate() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		let mut claims: Vec<Vec<u8>> = (0u8..4).map(|i| vec![i; 32]).collect();
+		for i in 0u8..4 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(sender), [i; 32], HashAlgorithm::Blake2_256, None));
+		}
+
+		let first = PoeModule::claims_of(&sender, 0, 3);
+		let rest = PoeModule::claims_of(&sender, 3, 3);
+		assert_eq!(first.len(), 3);
+		assert_eq!(rest.len(), 1);
+
+		let mut all = [first, rest].concat();
+		all.sort();
+		claims.sort();
+		assert_eq!(all, claims);
+		// the limit is capped at `MaxClaimsPerAccount`
+		assert_eq!(PoeModule::claims_of(&sender, 0, u32::MAX).len(), 4);
+		assert!(PoeModule::claims_of(&2, 0, 3).is_empty());
+	});
+}
+
+#[test]
+fn too_many_claims_should_fail() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		let receiver = 2;
+		for i in 0u8..4 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(sender), [i; 32], HashAlgorithm::Blake2_256, None));
+			assert_ok!(PoeModule::create_claim(Origin::signed(receiver), [i + 4; 32], HashAlgorithm::Blake2_256, None));
+		}
+		// can not claim beyond `MaxClaimsPerAccount`
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(sender), [9; 32], HashAlgorithm::Blake2_256, None),
+			Error::<Test>::TooManyClaims
+		);
+		// can not receive beyond `MaxClaimsPerAccount`
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(sender), vec![0; 32], receiver),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+#[test]
+fn batch_claims_should_work() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		let digests: Vec<[u8; 32]> = (0u8..3).map(|i| [i; 32]).collect();
+		let claims: Vec<Vec<u8>> = digests.iter().map(|digest| digest.to_vec()).collect();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claims(Origin::signed(sender), digests, HashAlgorithm::Blake2_256));
+		for claim in claims.iter() {
+			assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, sender);
+		}
+		assert_eq!(PoeModule::claim_count(sender), 3);
+
+		assert_ok!(PoeModule::revoke_claims(Origin::signed(sender), claims.clone()));
+		for claim in claims.iter() {
+			assert_eq!(Proofs::<Test>::get(&proof_of(&claim)), None);
+		}
+		assert_eq!(Balances::reserved_balance(sender), 3 * 5);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimRevoked(sender, claims[2].clone()))
+		);
+	});
+}
+
+#[test]
+fn batch_claims_should_be_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		let hacker = 2;
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		// one invalid digest fails the whole batch
+		assert_noop!(
+			PoeModule::create_claims(Origin::signed(sender), vec![digest, digest], HashAlgorithm::Blake2_256),
+			Error::<Test>::ProofAlreadyClaimed
+		);
+		// can not exceed `MaxBatchSize`
+		assert_noop!(
+			PoeModule::create_claims(Origin::signed(sender), (0u8..4).map(|i| [i; 32]).collect(), HashAlgorithm::Blake2_256),
+			Error::<Test>::BatchTooLarge
+		);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(hacker), [0; 32], HashAlgorithm::Blake2_256, None));
+		assert_noop!(
+			PoeModule::revoke_claims(Origin::signed(sender), vec![claim.clone(), vec![0; 32]]),
+			Error::<Test>::NotProofOwner
+		);
+		assert_noop!(
+			PoeModule::revoke_claims(Origin::signed(sender), vec![claim, vec![0; 33]]),
+			Error::<Test>::ProofTooLong
+		);
+	});
+}
+
+#[test]
+fn verify_inclusion_should_work() {
+	new_test_ext().execute_with(|| {
+		let sender = 1;
+		let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+		let nodes: Vec<[u8; 32]> = leaves.iter().map(PoeModule::hash_leaf).collect();
+		let node = PoeModule::hash_pair(&nodes[0], &nodes[1]);
+		let root = PoeModule::hash_pair(&node, &nodes[2]);
+		System::set_block_number(1);
+
+		// nothing is included before the root is anchored
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], vec![nodes[1], nodes[2]]));
+
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(sender), root, 3));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&root)).unwrap().owner, sender);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::MerkleRootAnchored(sender, root.to_vec(), 3))
+		);
+
+		assert!(PoeModule::verify_inclusion(root, leaves[0], vec![nodes[1], nodes[2]]));
+		assert!(PoeModule::verify_inclusion(root, leaves[1], vec![nodes[0], nodes[2]]));
+		assert!(PoeModule::verify_inclusion(root, leaves[2], vec![node]));
+		// wrong leaf or path
+		assert!(!PoeModule::verify_inclusion(root, [4u8; 32], vec![nodes[1], nodes[2]]));
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], vec![nodes[2], nodes[1]]));
+		// a path deeper than the tree is rejected
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], vec![nodes[1], nodes[2], root]));
+
+		// revoking the root drops the anchor
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(sender), root.to_vec()));
+		assert!(!PoeModule::verify_inclusion(root, leaves[2], vec![node]));
+	});
+}
+
+#[test]
+fn verify_inclusion_should_reject_inner_nodes() {
+	new_test_ext().execute_with(|| {
+		let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+		let nodes: Vec<[u8; 32]> = leaves.iter().map(PoeModule::hash_leaf).collect();
+		let node = PoeModule::hash_pair(&nodes[0], &nodes[1]);
+		let root = PoeModule::hash_pair(&node, &nodes[2]);
+		System::set_block_number(1);
+		assert_ok!(PoeModule::anchor_merkle_root(Origin::signed(1), root, 3));
+
+		// the root is no leaf of itself
+		assert!(!PoeModule::verify_inclusion(root, root, vec![]));
+		// nor is an inner node a leaf with a shorter path
+		assert!(!PoeModule::verify_inclusion(root, node, vec![nodes[2]]));
+		// and leaves must be given as documents, not as nodes
+		assert!(!PoeModule::verify_inclusion(root, nodes[2], vec![node]));
+	});
+}
+
+#[test]
+fn anchor_empty_merkle_root_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::anchor_merkle_root(Origin::signed(1), [0u8; 32], 0),
+			Error::<Test>::EmptyMerkleTree
+		);
+	});
+}
+
+#[test]
+fn offer_and_accept_claim_should_work() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let receiver = 2;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::offer_claim(Origin::signed(sender), claim.clone(), receiver));
+		assert_eq!(PoeModule::pending_transfers(&proof_of(&claim)), Some((receiver, 6)));
+		// ownership does not change until the receiver accepts
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, sender);
+
+		assert_ok!(PoeModule::accept_claim(Origin::signed(receiver), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, receiver);
+		assert_eq!(PoeModule::pending_transfers(&proof_of(&claim)), None);
+		assert_eq!(Balances::reserved_balance(receiver), 42);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimTransferred(sender, receiver, claim, 0))
+		);
+	});
+}
+
+#[test]
+fn accept_claim_should_fail() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let receiver = 2;
+		let hacker = 3;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(receiver), claim.clone()),
+			Error::<Test>::NoSuchOffer
+		);
+		// only the owner can offer
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(hacker), claim.clone(), hacker),
+			Error::<Test>::NotProofOwner
+		);
+
+		assert_ok!(PoeModule::offer_claim(Origin::signed(sender), claim.clone(), receiver));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(hacker), claim.clone()),
+			Error::<Test>::NotOfferReceiver
+		);
+
+		// the offer lapses after `OfferDuration` blocks
+		System::set_block_number(6);
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(receiver), claim.clone()),
+			Error::<Test>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn cancel_offer_should_work() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let receiver = 2;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::offer_claim(Origin::signed(sender), claim.clone(), receiver));
+		assert_noop!(
+			PoeModule::cancel_offer(Origin::signed(receiver), claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		assert_ok!(PoeModule::cancel_offer(Origin::signed(sender), claim.clone()));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimOfferCancelled(sender, claim.clone()))
+		);
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(receiver), claim.clone()),
+			Error::<Test>::NoSuchOffer
+		);
+		assert_noop!(
+			PoeModule::cancel_offer(Origin::signed(sender), claim.clone()),
+			Error::<Test>::NoSuchOffer
+		);
+
+		// a direct transfer drops the offer as well
+		assert_ok!(PoeModule::offer_claim(Origin::signed(sender), claim.clone(), receiver));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(sender), claim.clone(), 3));
+		assert_eq!(PoeModule::pending_transfers(&proof_of(&claim)), None);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn claim_history_should_record_owners() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let receiver = 2;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		System::set_block_number(4);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(sender), claim.clone(), receiver));
+
+		// the creation block survives the transfer
+		let info = Proofs::<Test>::get(&proof_of(&claim)).unwrap();
+		assert_eq!((info.created_at, info.block_number), (1, 4));
+		assert_eq!(
+			PoeModule::claim_history(&proof_of(&claim)).to_vec(),
+			vec![
+				ProvenanceRecord { owner: sender, block_number: 1, action: ClaimAction::Created },
+				ProvenanceRecord { owner: receiver, block_number: 4, action: ClaimAction::Transferred },
+			]
+		);
+
+		// the log outlives the claim and records how it ended
+		System::set_block_number(6);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(receiver), claim.clone()));
+		assert_eq!(
+			PoeModule::claim_history(&proof_of(&claim)).to_vec(),
+			vec![
+				ProvenanceRecord { owner: sender, block_number: 1, action: ClaimAction::Created },
+				ProvenanceRecord { owner: receiver, block_number: 4, action: ClaimAction::Transferred },
+				ProvenanceRecord { owner: receiver, block_number: 6, action: ClaimAction::Revoked },
+			]
+		);
+	});
+}
+
+#[test]
+fn full_history_should_drop_its_oldest_entry() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 3));
+		// `MaxHistoryLength` entries are recorded already, yet the claim still changes hands
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(3), claim.clone(), 1));
+		assert_ok!(PoeModule::force_transfer(Origin::root(), DEFAULT_REGISTRY, claim.clone(), 2));
+		assert_eq!(
+			PoeModule::claim_history(&proof_of(&claim)).to_vec(),
+			vec![
+				ProvenanceRecord { owner: 3, block_number: 1, action: ClaimAction::Transferred },
+				ProvenanceRecord { owner: 1, block_number: 1, action: ClaimAction::Transferred },
+				ProvenanceRecord { owner: 2, block_number: 1, action: ClaimAction::Transferred },
+			]
+		);
+	});
+}
+
+#[test]
+fn claims_should_record_the_hash_algorithm() {
+	new_test_ext().execute_with(|| {
+		let document = b"contract".to_vec();
+		let sha2_digest = HashAlgorithm::Sha2_256.hash(&document);
+		let keccak_digest = HashAlgorithm::Keccak256.hash(&document);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), sha2_digest, HashAlgorithm::Sha2_256, None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), keccak_digest, HashAlgorithm::Keccak256, None));
+		assert_eq!(
+			Proofs::<Test>::get(&proof_of(&sha2_digest)).unwrap().hash_algorithm,
+			Some(HashAlgorithm::Sha2_256)
+		);
+
+		// the document can be checked against either claim
+		assert!(PoeModule::matches_preimage(&sha2_digest.to_vec(), &document));
+		assert!(PoeModule::matches_preimage(&keccak_digest.to_vec(), &document));
+		assert!(!PoeModule::matches_preimage(&sha2_digest.to_vec(), b"forgery"));
+		// but not against an unclaimed digest
+		let blake2_digest = HashAlgorithm::Blake2_256.hash(&document);
+		assert!(!PoeModule::matches_preimage(&blake2_digest.to_vec(), &document));
+	});
+}
+
+#[test]
+fn joint_claim_should_need_approvals_to_revoke() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2, 3], 2));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::CoOwnersSet(1, claim.clone(), 2))
+		);
+
+		// the first approval is recorded but the claim stays
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+		assert!(Proofs::<Test>::contains_key(&proof_of(&claim)));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ActionApproved(1, claim.clone(), 1))
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(4), claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+
+		// the second approval revokes the claim and returns the deposit to its owner
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)), None);
+		assert_eq!(PoeModule::co_owners(&proof_of(&claim)), None);
+		assert_eq!(PoeModule::approvals(&proof_of(&claim)), None);
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimRevoked(3, claim))
+		);
+	});
+}
+
+#[test]
+fn joint_claim_should_need_approvals_to_transfer() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2, 3], 2));
+
+		// approvals only count towards the same receiver, and no other action can start meanwhile
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 3));
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(3), claim.clone(), 2),
+			Error::<Test>::ActionPending
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(3), claim.clone()),
+			Error::<Test>::ActionPending
+		);
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, 1);
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, 3);
+		assert_eq!(Balances::reserved_balance(3), 42);
+		// the receiver owns the claim alone
+		assert_eq!(PoeModule::co_owners(&proof_of(&claim)), None);
+		assert_eq!(PoeModule::approvals(&proof_of(&claim)), None);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+	});
+}
+
+#[test]
+fn joint_claim_approvals_should_expire() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2], 2));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+		assert_eq!(
+			PoeModule::approvals(&proof_of(&claim)),
+			Some((JointAction::Revoke, vec![1].try_into().unwrap(), Default::default(), 6))
+		);
+
+		// the approval of account 1 lapsed after `ApprovalPeriod` blocks, and another action
+		// may start
+		System::set_block_number(6);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 3));
+		assert!(Proofs::<Test>::contains_key(&proof_of(&claim)));
+		assert_eq!(
+			PoeModule::approvals(&proof_of(&claim)),
+			Some((JointAction::Transfer(3), vec![2].try_into().unwrap(), Default::default(), 11))
+		);
+	});
+}
+
+#[test]
+fn joint_claim_actions_should_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2, 3], 2));
+		assert_noop!(
+			PoeModule::cancel_action(Origin::signed(2), claim.clone()),
+			Error::<Test>::NoPendingAction
+		);
+
+		// the co-owner who opened the action withdraws its approval, which cancels it
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 2));
+		assert_noop!(
+			PoeModule::cancel_action(Origin::signed(4), claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		assert_ok!(PoeModule::cancel_action(Origin::signed(2), claim.clone()));
+		assert_eq!(PoeModule::approvals(&proof_of(&claim)), None);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ActionCancelled(claim.clone()))
+		);
+
+		// a single objection leaves the action open while it can still pass
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 2));
+		assert_ok!(PoeModule::cancel_action(Origin::signed(3), claim.clone()));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ActionObjected(3, claim.clone(), 1))
+		);
+		assert_noop!(
+			PoeModule::cancel_action(Origin::signed(3), claim.clone()),
+			Error::<Test>::AlreadyObjected
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+			Error::<Test>::ActionPending
+		);
+
+		// once it can no longer reach the threshold, the action is cancelled
+		assert_ok!(PoeModule::cancel_action(Origin::signed(1), claim.clone()));
+		assert_eq!(PoeModule::approvals(&proof_of(&claim)), None);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ActionCancelled(claim.clone()))
+		);
+
+		// and the co-owners may approve another one
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)), None);
+	});
+}
+
+#[test]
+fn set_co_owners_should_fail() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+		assert_noop!(
+			PoeModule::set_co_owners(Origin::signed(2), claim.clone(), vec![2], 1),
+			Error::<Test>::NotProofOwner
+		);
+		assert_noop!(
+			PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2], 0),
+			Error::<Test>::InvalidThreshold
+		);
+		// the owner is counted once
+		assert_noop!(
+			PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![1, 2], 3),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2, 3, 4], 2),
+			Error::<Test>::TooManyCoOwners
+		);
+
+		assert_ok!(PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![2], 2));
+		assert_noop!(
+			PoeModule::set_co_owners(Origin::signed(1), claim.clone(), vec![3], 1),
+			Error::<Test>::JointlyOwned
+		);
+		// an offer would bypass the co-owners
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(1), claim, 2),
+			Error::<Test>::JointlyOwned
+		);
+	});
+}
+
+#[test]
+fn delegate_should_manage_claims_for_owner() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let owner = 1;
+		let delegate = 2;
+		let permissions = DelegatePermissions { create: true, revoke: true, transfer: true };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::add_delegate(Origin::signed(owner), delegate, permissions, None));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::DelegateAdded(owner, delegate))
+		);
+
+		// the owner holds the claim and pays its deposit
+		assert_ok!(PoeModule::create_claim_for(Origin::signed(delegate), owner, digest, HashAlgorithm::Blake2_256, None));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, owner);
+		assert_eq!(Balances::reserved_balance(owner), 42);
+		assert_eq!(Balances::reserved_balance(delegate), 0);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::DelegateActed(delegate, owner, claim.clone()))
+		);
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(delegate), claim.clone(), 3));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, 3);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::PoeModule(crate::Event::DelegateActed(delegate, owner, claim.clone()))));
+
+		// the delegation only covers the claims of the owner
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(delegate), claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+	});
+}
+
+#[test]
+fn delegate_should_be_limited_by_permissions_and_expiry() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let owner = 1;
+		let delegate = 2;
+		let permissions = DelegatePermissions { revoke: true, ..Default::default() };
+		System::set_block_number(1);
+		assert_noop!(
+			PoeModule::add_delegate(Origin::signed(owner), delegate, permissions, Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(PoeModule::add_delegate(Origin::signed(owner), delegate, permissions, Some(5)));
+		assert_ok!(PoeModule::create_claim(Origin::signed(owner), digest, HashAlgorithm::Blake2_256, None));
+
+		assert_noop!(
+			PoeModule::create_claim_for(Origin::signed(delegate), owner, [0; 32], HashAlgorithm::Blake2_256, None),
+			Error::<Test>::NotDelegate
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(delegate), claim.clone(), delegate),
+			Error::<Test>::NotProofOwner
+		);
+
+		// the delegation lapses at its expiry block
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(delegate), claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+
+		// and can be withdrawn by the owner
+		assert_ok!(PoeModule::remove_delegate(Origin::signed(owner), delegate));
+		assert_eq!(PoeModule::delegates(owner, delegate), None);
+		assert_noop!(
+			PoeModule::remove_delegate(Origin::signed(owner), delegate),
+			Error::<Test>::NotDelegate
+		);
+	});
+}
+
+#[test]
+fn create_claim_on_behalf_should_work() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let relayer = 1;
+		// the owner holds no funds
+		let owner = 5;
+		let payload = PoeModule::on_behalf_payload(digest, HashAlgorithm::Blake2_256, 10, 0);
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim_on_behalf(
+			Origin::signed(relayer),
+			digest,
+			HashAlgorithm::Blake2_256,
+			owner,
+			TestSignature(owner, payload.clone()),
+			10,
+		));
+
+		// the relayer funds the deposit, which stays with the owner, and its existential deposit
+		let info = Proofs::<Test>::get(&proof_of(&claim)).unwrap();
+		assert_eq!(info.owner, owner);
+		assert_eq!(Balances::reserved_balance(owner), 42);
+		assert_eq!(Balances::free_balance(owner), 1);
+		assert_eq!(Balances::free_balance(relayer), 957);
+		assert_eq!(PoeModule::nonce(owner), 1);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimCreated(owner, claim.clone(), 0))
+		);
+
+		// the same signature can not be replayed
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(owner), claim));
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(
+				Origin::signed(relayer),
+				digest,
+				HashAlgorithm::Blake2_256,
+				owner,
+				TestSignature(owner, payload),
+				10,
+			),
+			Error::<Test>::BadSignature
+		);
+	});
+}
+
+#[test]
+fn create_claim_on_behalf_should_fund_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		// the existential deposit is larger than the claim deposit
+		set_existential_deposit(100);
+		let relayer = 1;
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let payload = PoeModule::on_behalf_payload(digest, HashAlgorithm::Blake2_256, 10, 0);
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim_on_behalf(
+			Origin::signed(relayer),
+			digest,
+			HashAlgorithm::Blake2_256,
+			5,
+			TestSignature(5, payload),
+			10,
+		));
+		assert_eq!(Balances::reserved_balance(5), 42);
+		assert_eq!(Balances::free_balance(5), 100);
+		assert_eq!(Balances::free_balance(relayer), 858);
+
+		// an owner with an account only gets the claim deposit
+		let digest = HashAlgorithm::Blake2_256.hash(b"another claim");
+		let payload = PoeModule::on_behalf_payload(digest, HashAlgorithm::Blake2_256, 10, 1);
+		assert_ok!(PoeModule::create_claim_on_behalf(
+			Origin::signed(relayer),
+			digest,
+			HashAlgorithm::Blake2_256,
+			5,
+			TestSignature(5, payload),
+			10,
+		));
+		assert_eq!(Balances::reserved_balance(5), 84);
+		assert_eq!(Balances::free_balance(5), 100);
+		assert_eq!(Balances::free_balance(relayer), 816);
+	});
+}
+
+#[test]
+fn create_claim_on_behalf_should_fail() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let relayer = 1;
+		let owner = 5;
+		let payload = PoeModule::on_behalf_payload(digest, HashAlgorithm::Blake2_256, 10, 0);
+		System::set_block_number(1);
+		// signed by someone else
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(
+				Origin::signed(relayer),
+				digest,
+				HashAlgorithm::Blake2_256,
+				owner,
+				TestSignature(relayer, payload.clone()),
+				10,
+			),
+			Error::<Test>::BadSignature
+		);
+		// signed for another algorithm
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(
+				Origin::signed(relayer),
+				digest,
+				HashAlgorithm::Sha2_256,
+				owner,
+				TestSignature(owner, payload.clone()),
+				10,
+			),
+			Error::<Test>::BadSignature
+		);
+		// signed without the context of the call
+		let untagged = (digest, HashAlgorithm::Blake2_256, 10u64, 0u64).encode();
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(
+				Origin::signed(relayer),
+				digest,
+				HashAlgorithm::Blake2_256,
+				owner,
+				TestSignature(owner, untagged),
+				10,
+			),
+			Error::<Test>::BadSignature
+		);
+		// signed for another chain
+		let genesis_hash = H256::repeat_byte(1);
+		let other_chain = (ON_BEHALF_CONTEXT, genesis_hash, digest, HashAlgorithm::Blake2_256, 10u64, 0u64).encode();
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(
+				Origin::signed(relayer),
+				digest,
+				HashAlgorithm::Blake2_256,
+				owner,
+				TestSignature(owner, other_chain),
+				10,
+			),
+			Error::<Test>::BadSignature
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			PoeModule::create_claim_on_behalf(
+				Origin::signed(relayer),
+				digest,
+				HashAlgorithm::Blake2_256,
+				owner,
+				TestSignature(owner, payload),
+				10,
+			),
+			Error::<Test>::SignatureExpired
+		);
+	});
+}
+
+#[test]
+fn frozen_claim_should_reject_owner_actions() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let owner = 1;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(owner), digest, HashAlgorithm::Blake2_256, None));
+		assert_noop!(
+			PoeModule::freeze_claim(Origin::signed(owner), DEFAULT_REGISTRY, claim.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), DEFAULT_REGISTRY, claim.clone()));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimFrozen(DEFAULT_REGISTRY, claim.clone()))
+		);
+
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(owner), claim.clone(), 2),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(owner), claim.clone()),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(owner), claim.clone(), 2),
+			Error::<Test>::ClaimFrozen
+		);
+
+		assert_ok!(PoeModule::unfreeze_claim(Origin::root(), DEFAULT_REGISTRY, claim.clone()));
+		assert_noop!(
+			PoeModule::unfreeze_claim(Origin::root(), DEFAULT_REGISTRY, claim.clone()),
+			Error::<Test>::NotFrozen
+		);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(owner), claim, 2));
+	});
+}
+
+#[test]
+fn force_origin_should_override_the_owner() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let owner = 1;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(owner), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), DEFAULT_REGISTRY, claim.clone()));
+
+		// the claim and its deposit move to the rightful owner, still frozen
+		assert_ok!(PoeModule::force_transfer(Origin::root(), DEFAULT_REGISTRY, claim.clone(), 2));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(2), 42);
+		assert!(FrozenClaims::<Test>::contains_key(&proof_of(&claim)));
+
+		assert_noop!(
+			PoeModule::force_revoke(Origin::signed(2), DEFAULT_REGISTRY, claim.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::force_revoke(Origin::root(), DEFAULT_REGISTRY, claim.clone()));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)), None);
+		assert!(!FrozenClaims::<Test>::contains_key(&proof_of(&claim)));
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimForceRevoked(2, DEFAULT_REGISTRY, claim))
+		);
+	});
+}
+
+#[test]
+fn genesis_claims_should_be_imported() {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		claims: vec![(vec![1; 32], 1), (vec![2; 4], 2)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let info = Proofs::<Test>::get(&proof_of(&vec![1; 32])).unwrap();
+		assert_eq!((info.owner, info.created_at, info.deposit), (1, 0, 0));
+		assert_eq!(Proofs::<Test>::get(&proof_of(&vec![2; 4])).unwrap().owner, 2);
+		assert_eq!(PoeModule::claims_of(&1, 0, 10), vec![vec![1; 32]]);
+		assert_eq!(PoeModule::claim_history(&proof_of(&vec![2; 4])).len(), 1);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Duplicate proof in genesis claims")]
+fn duplicate_genesis_claims_should_fail() {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		claims: vec![(vec![1; 32], 1), (vec![1; 32], 2)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Genesis claim proof must be within ProofMinLength and ProofMaxLength")]
+fn invalid_genesis_claims_should_fail() {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		claims: vec![(vec![1; 33], 1)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
+
+#[test]
+fn transfer_should_record_the_time() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		Timestamp::set_timestamp(6_000);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, None));
+
+		System::set_block_number(2);
+		Timestamp::set_timestamp(12_000);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::ClaimTransferred(1, 2, claim.clone(), 12_000))
+		);
+		// the claim keeps the time it was created at
+		assert_eq!(Proofs::<Test>::get(&proof_of(&claim)).unwrap().timestamp, 6_000);
+	});
+}
+
+#[test]
+fn revoked_claims_should_be_remembered() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let sender = 1;
+		let other = 2;
+		System::set_block_number(1);
+		assert_eq!(PoeModule::claim_status(&claim), ClaimStatus::Unclaimed);
+		assert_ok!(PoeModule::create_claim(Origin::signed(sender), digest, HashAlgorithm::Blake2_256, None));
+		assert_eq!(PoeModule::claim_status(&claim), ClaimStatus::Claimed);
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(sender), claim.clone()));
+		assert_eq!(
+			PoeModule::claim_status(&claim),
+			ClaimStatus::Revoked(RevocationRecord { owner: sender, revoker: Some(sender), block_number: 2, deposit: 5 })
+		);
+
+		// nobody can claim the proof during the cooldown
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(other), digest, HashAlgorithm::Blake2_256, None),
+			Error::<Test>::RevokedRecently
+		);
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim(Origin::signed(other), digest, HashAlgorithm::Blake2_256, None));
+		assert_eq!(PoeModule::claim_status(&claim), ClaimStatus::Claimed);
+		assert_eq!(PoeModule::revoked(&proof_of(&claim)), None);
+		// claiming the proof again clears the record and its deposit
+		assert_eq!(Balances::reserved_balance(sender), 0);
+
+		// force revocations are recorded as well
+		assert_ok!(PoeModule::force_revoke(Origin::root(), DEFAULT_REGISTRY, claim.clone()));
+		assert_eq!(
+			PoeModule::claim_status(&claim),
+			ClaimStatus::Revoked(RevocationRecord { owner: other, revoker: None, block_number: 5, deposit: 5 })
+		);
+	});
+}
+
+#[test]
+fn clear_revocation_should_return_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let owner = 1;
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(owner), digest, HashAlgorithm::Blake2_256, None));
+		assert_noop!(
+			PoeModule::clear_revocation(Origin::signed(2), DEFAULT_REGISTRY, claim.clone()),
+			Error::<Test>::NotRevoked
+		);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(owner), claim.clone()));
+		assert_eq!(Balances::reserved_balance(owner), 5);
+
+		// the record stays for the cooldown
+		assert_noop!(
+			PoeModule::clear_revocation(Origin::signed(2), DEFAULT_REGISTRY, claim.clone()),
+			Error::<Test>::RevokedRecently
+		);
+		System::set_block_number(4);
+		assert_ok!(PoeModule::clear_revocation(Origin::signed(2), DEFAULT_REGISTRY, claim.clone()));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::RevocationCleared(DEFAULT_REGISTRY, claim.clone()))
+		);
+		assert_eq!(PoeModule::claim_status(&claim), ClaimStatus::Unclaimed);
+		assert_eq!(Balances::reserved_balance(owner), 0);
+		assert_eq!(Balances::free_balance(owner), 1000);
+
+		// records in other registries are cleared the same way
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Anyone, max_proof_length: 32 };
+		assert_ok!(PoeModule::create_registry(Origin::signed(2), policy));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(owner), 1, digest, HashAlgorithm::Blake2_256));
+		assert_ok!(PoeModule::revoke_registry_claim(Origin::signed(owner), 1, claim.clone()));
+		System::set_block_number(7);
+		assert_ok!(PoeModule::clear_revocation(Origin::signed(2), 1, claim.clone()));
+		assert_eq!(PoeModule::revoked_registry_claims(1, &proof_of(&claim)), None);
+		assert_eq!(Balances::reserved_balance(owner), 0);
+	});
+}
+
+#[test]
+fn create_claim_with_content_should_index_the_content() {
+	let mut ext = new_test_ext();
+	let content = b"the quick brown fox".to_vec();
+	let digest = HashAlgorithm::Blake2_256.hash(&content);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim_with_content(Origin::signed(1), content.clone()));
+		let info = Proofs::<Test>::get(&proof_of(&digest)).unwrap();
+		assert_eq!((info.owner, info.hash_algorithm), (1, Some(HashAlgorithm::Blake2_256)));
+
+		// the same content can't be claimed twice
+		assert_noop!(
+			PoeModule::create_claim_with_content(Origin::signed(2), content.clone()),
+			Error::<Test>::ProofAlreadyClaimed
+		);
+		assert_noop!(
+			PoeModule::create_claim_with_content(Origin::signed(1), vec![0u8; 65]),
+			Error::<Test>::ContentTooLong
+		);
+	});
+
+	ext.persist_offchain_overlay();
+	assert_eq!(ext.offchain_db().get(&content_key(&digest)), Some(content));
+}
+
+#[test]
+fn claim_changes_should_be_notified() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), digest, HashAlgorithm::Blake2_256, Some(3)));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+		// failed calls notify nothing
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		PoeModule::on_initialize(3);
+
+		assert_eq!(claim_changes(), vec![
+			ClaimChange::Created(DEFAULT_REGISTRY, 1, claim.clone()),
+			ClaimChange::Transferred(DEFAULT_REGISTRY, 1, 2, claim.clone()),
+			ClaimChange::Revoked(DEFAULT_REGISTRY, 2, claim),
+		]);
+	});
+}
+
+#[test]
+fn registry_claim_changes_should_be_notified() {
+	new_test_ext().execute_with(|| {
+		let digest = [1u8; 32];
+		let claim = digest.to_vec();
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Anyone, max_proof_length: 32 };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(2), 1, digest, HashAlgorithm::Blake2_256));
+		assert_ok!(PoeModule::transfer_registry_claim(Origin::signed(2), 1, claim.clone(), 3));
+		assert_ok!(PoeModule::revoke_registry_claim(Origin::signed(3), 1, claim.clone()));
+
+		assert_eq!(claim_changes(), vec![
+			ClaimChange::Created(1, 2, claim.clone()),
+			ClaimChange::Transferred(1, 2, 3, claim.clone()),
+			ClaimChange::Revoked(1, 3, claim),
+		]);
+	});
+}
+
+#[test]
+fn registries_should_be_administered_by_their_creator() {
+	new_test_ext().execute_with(|| {
+		let admin = 1;
+		let member = 2;
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Members, max_proof_length: 8 };
+		System::set_block_number(1);
+
+		assert_noop!(
+			PoeModule::create_registry(Origin::signed(admin), RegistryPolicy { max_proof_length: 33, ..policy }),
+			Error::<Test>::InvalidPolicy
+		);
+		assert_ok!(PoeModule::create_registry(Origin::signed(admin), policy));
+		assert_eq!(last_event(), Event::PoeModule(crate::Event::RegistryCreated(admin, 1)));
+		assert_eq!(PoeModule::registries(1), Some(RegistryInfo { admin, deposit: 100, policy }));
+		assert_eq!(Balances::free_balance(admin), 900);
+
+		// only the admin changes the policy and the members
+		let open = RegistryPolicy { claimers: ClaimPolicy::Anyone, ..policy };
+		assert_noop!(
+			PoeModule::set_registry_policy(Origin::signed(member), 1, open),
+			Error::<Test>::NotRegistryAdmin
+		);
+		assert_noop!(
+			PoeModule::set_registry_policy(Origin::signed(admin), 2, open),
+			Error::<Test>::NoSuchRegistry
+		);
+		assert_ok!(PoeModule::set_registry_policy(Origin::signed(admin), 1, open));
+		assert_eq!(PoeModule::registries(1).unwrap().policy, open);
+
+		assert_noop!(
+			PoeModule::add_registry_member(Origin::signed(member), 1, member),
+			Error::<Test>::NotRegistryAdmin
+		);
+		assert_ok!(PoeModule::add_registry_member(Origin::signed(admin), 1, member));
+		assert_noop!(
+			PoeModule::add_registry_member(Origin::signed(admin), 1, member),
+			Error::<Test>::AlreadyMember
+		);
+		assert_ok!(PoeModule::remove_registry_member(Origin::signed(admin), 1, member));
+		assert_noop!(
+			PoeModule::remove_registry_member(Origin::signed(admin), 1, member),
+			Error::<Test>::NotMember
+		);
+
+		// the default registry can't be administered
+		assert_noop!(
+			PoeModule::add_registry_member(Origin::signed(admin), DEFAULT_REGISTRY, member),
+			Error::<Test>::NoSuchRegistry
+		);
+	});
+}
+
+#[test]
+fn registries_should_keep_claims_apart() {
+	new_test_ext().execute_with(|| {
+		let digest = HashAlgorithm::Blake2_256.hash(b"claim");
+		let claim = digest.to_vec();
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Members, max_proof_length: 32 };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		assert_ok!(PoeModule::create_registry(Origin::signed(2), RegistryPolicy { claimers: ClaimPolicy::Anyone, max_proof_length: 8 }));
+
+		// the same proof is claimed once per registry
+		assert_ok!(PoeModule::create_claim(Origin::signed(3), digest, HashAlgorithm::Blake2_256, None));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(1), 1, digest, HashAlgorithm::Blake2_256));
+		assert_eq!(
+			last_event(),
+			Event::PoeModule(crate::Event::RegistryClaimCreated(1, 1, claim.clone(), 0))
+		);
+		assert_noop!(
+			PoeModule::create_registry_claim(Origin::signed(1), 1, digest, HashAlgorithm::Blake2_256),
+			Error::<Test>::ProofAlreadyClaimed
+		);
+		assert_eq!(PoeModule::registry_claim(DEFAULT_REGISTRY, claim.clone()).unwrap().owner, 3);
+		let info = PoeModule::registry_claim(1, claim.clone()).unwrap();
+		assert_eq!((info.owner, info.hash_algorithm), (1, Some(HashAlgorithm::Blake2_256)));
+		assert_eq!(Balances::reserved_balance(1), 100 + 42);
+
+		// the policy of the registry applies
+		assert_noop!(
+			PoeModule::create_registry_claim(Origin::signed(3), 1, [1; 32], HashAlgorithm::Blake2_256),
+			Error::<Test>::NotAllowedToClaim
+		);
+		assert_noop!(
+			PoeModule::create_registry_claim(Origin::signed(3), 2, digest, HashAlgorithm::Blake2_256),
+			Error::<Test>::ProofTooLong
+		);
+		let open = RegistryPolicy { claimers: ClaimPolicy::Anyone, max_proof_length: 32 };
+		assert_ok!(PoeModule::set_registry_policy(Origin::signed(2), 2, open));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(3), 2, [1; 32], HashAlgorithm::Blake2_256));
+		assert_noop!(
+			PoeModule::create_registry_claim(Origin::signed(3), DEFAULT_REGISTRY, [1; 32], HashAlgorithm::Blake2_256),
+			Error::<Test>::NoSuchRegistry
+		);
+
+		// claims only go to accounts allowed to claim
+		assert_noop!(
+			PoeModule::transfer_registry_claim(Origin::signed(1), 1, claim.clone(), 2),
+			Error::<Test>::NotAllowedToClaim
+		);
+		assert_ok!(PoeModule::add_registry_member(Origin::signed(1), 1, 2));
+		assert_noop!(
+			PoeModule::transfer_registry_claim(Origin::signed(2), 1, claim.clone(), 1),
+			Error::<Test>::NotProofOwner
+		);
+		assert_ok!(PoeModule::transfer_registry_claim(Origin::signed(1), 1, claim.clone(), 2));
+		assert_eq!(PoeModule::registry_claim(1, claim.clone()).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(2), 100 + 42);
+
+		// revoking leaves the other registries alone
+		assert_noop!(
+			PoeModule::revoke_registry_claim(Origin::signed(3), 1, claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		assert_ok!(PoeModule::revoke_registry_claim(Origin::signed(2), 1, claim.clone()));
+		assert_eq!(PoeModule::registry_claim(1, claim.clone()), None);
+		assert_eq!(Balances::reserved_balance(2), 100 + 5);
+		assert!(PoeModule::is_claimed(&claim));
+	});
+}
+
+#[test]
+fn registry_claims_should_count_towards_max_claims() {
+	new_test_ext().execute_with(|| {
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Anyone, max_proof_length: 32 };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		for i in 0u8..2 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(2), [i; 32], HashAlgorithm::Blake2_256, None));
+			assert_ok!(PoeModule::create_registry_claim(Origin::signed(2), 1, [i; 32], HashAlgorithm::Blake2_256));
+		}
+		assert_eq!(PoeModule::claim_count(2), 4);
+		assert!(RegistryClaimsByOwner::<Test>::contains_key(2, (1, proof_of(&[0; 32]))));
+
+		// `MaxClaimsPerAccount` spans every registry
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(2), [2; 32], HashAlgorithm::Blake2_256, None),
+			Error::<Test>::TooManyClaims
+		);
+		assert_noop!(
+			PoeModule::create_registry_claim(Origin::signed(2), 1, [2; 32], HashAlgorithm::Blake2_256),
+			Error::<Test>::TooManyClaims
+		);
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(3), 1, [2; 32], HashAlgorithm::Blake2_256));
+		assert_noop!(
+			PoeModule::transfer_registry_claim(Origin::signed(3), 1, vec![2; 32], 2),
+			Error::<Test>::TooManyClaims
+		);
+
+		assert_ok!(PoeModule::revoke_registry_claim(Origin::signed(2), 1, vec![0; 32]));
+		assert_eq!(PoeModule::claim_count(2), 3);
+		assert!(!RegistryClaimsByOwner::<Test>::contains_key(2, (1, proof_of(&[0; 32]))));
+		assert_ok!(PoeModule::transfer_registry_claim(Origin::signed(3), 1, vec![2; 32], 2));
+		assert_eq!(PoeModule::claim_count(2), 4);
+		assert_eq!(PoeModule::claim_count(3), 0);
+	});
+}
+
+#[test]
+fn registry_claims_should_be_frozen_and_forced() {
+	new_test_ext().execute_with(|| {
+		let digest = [1u8; 32];
+		let claim = digest.to_vec();
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Members, max_proof_length: 32 };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		assert_ok!(PoeModule::add_registry_member(Origin::signed(1), 1, 2));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(2), 1, digest, HashAlgorithm::Blake2_256));
+
+		// the freeze applies to the claim in its registry only
+		assert_noop!(
+			PoeModule::freeze_claim(Origin::root(), DEFAULT_REGISTRY, claim.clone()),
+			Error::<Test>::NoSuchProof
+		);
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), 1, claim.clone()));
+		assert_eq!(last_event(), Event::PoeModule(crate::Event::ClaimFrozen(1, claim.clone())));
+		assert_noop!(
+			PoeModule::revoke_registry_claim(Origin::signed(2), 1, claim.clone()),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			PoeModule::transfer_registry_claim(Origin::signed(2), 1, claim.clone(), 1),
+			Error::<Test>::ClaimFrozen
+		);
+
+		// the `ForceOrigin` overrides both the freeze and the policy of the registry
+		assert_ok!(PoeModule::force_transfer(Origin::root(), 1, claim.clone(), 3));
+		assert_eq!(PoeModule::registry_claim(1, claim.clone()).unwrap().owner, 3);
+		assert_eq!(Balances::reserved_balance(3), 42);
+		assert_ok!(PoeModule::force_revoke(Origin::root(), 1, claim.clone()));
+		assert_eq!(last_event(), Event::PoeModule(crate::Event::ClaimForceRevoked(3, 1, claim.clone())));
+		assert_eq!(PoeModule::registry_claim(1, claim.clone()), None);
+		assert!(!FrozenRegistryClaims::<Test>::contains_key(1, &proof_of(&claim)));
+		assert_eq!(Balances::reserved_balance(3), 5);
+		assert_eq!(PoeModule::claim_count(3), 0);
+	});
+}
+
+#[test]
+fn revoked_registry_claims_should_cool_down() {
+	new_test_ext().execute_with(|| {
+		let digest = [1u8; 32];
+		let claim = digest.to_vec();
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Anyone, max_proof_length: 32 };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(2), 1, digest, HashAlgorithm::Blake2_256));
+		assert_ok!(PoeModule::revoke_registry_claim(Origin::signed(2), 1, claim.clone()));
+		assert_eq!(
+			PoeModule::revoked_registry_claims(1, &proof_of(&claim)),
+			Some(RevocationRecord { owner: 2, revoker: Some(2), block_number: 1, deposit: 5 })
+		);
+
+		// the cooldown is kept per registry
+		assert_noop!(
+			PoeModule::create_registry_claim(Origin::signed(3), 1, digest, HashAlgorithm::Blake2_256),
+			Error::<Test>::RevokedRecently
+		);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(3), 2, digest, HashAlgorithm::Blake2_256));
+
+		System::set_block_number(4);
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(3), 1, digest, HashAlgorithm::Blake2_256));
+		assert_eq!(PoeModule::revoked_registry_claims(1, &proof_of(&claim)), None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn destroy_registry_should_return_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let digest = [1u8; 32];
+		let claim = digest.to_vec();
+		let policy = RegistryPolicy { claimers: ClaimPolicy::Members, max_proof_length: 32 };
+		System::set_block_number(1);
+		assert_ok!(PoeModule::create_registry(Origin::signed(1), policy));
+		assert_ok!(PoeModule::add_registry_member(Origin::signed(1), 1, 2));
+		assert_ok!(PoeModule::create_registry_claim(Origin::signed(2), 1, digest, HashAlgorithm::Blake2_256));
+
+		assert_noop!(PoeModule::destroy_registry(Origin::signed(2), 1), Error::<Test>::NotRegistryAdmin);
+		assert_noop!(PoeModule::destroy_registry(Origin::signed(1), 2), Error::<Test>::NoSuchRegistry);
+
+		// claims and members go first, and the admin may revoke the claims of members
+		assert_noop!(PoeModule::destroy_registry(Origin::signed(1), 1), Error::<Test>::RegistryInUse);
+		assert_ok!(PoeModule::revoke_registry_claim(Origin::signed(1), 1, claim.clone()));
+		assert_eq!(
+			PoeModule::revoked_registry_claims(1, &proof_of(&claim)),
+			Some(RevocationRecord { owner: 2, revoker: Some(1), block_number: 1, deposit: 5 })
+		);
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_noop!(PoeModule::destroy_registry(Origin::signed(1), 1), Error::<Test>::RegistryInUse);
+		assert_ok!(PoeModule::remove_registry_member(Origin::signed(1), 1, 2));
+
+		assert_ok!(PoeModule::destroy_registry(Origin::signed(1), 1));
+		assert_eq!(last_event(), Event::PoeModule(crate::Event::RegistryDestroyed(1, 1)));
+		assert_eq!(PoeModule::registries(1), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1000);
+	});
+}
@@ -1,40 +1,15 @@
-// This file is part of Substrate.
-
-// Copyright (C) 2021 Parity Technologies (UK) Ltd.
-// SPDX-License-Identifier: Apache-2.0
-
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-// http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-//! Autogenerated weights for pallet_poe
+//! Weights for pallet_poe
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-09-26, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet-poe
-// --extrinsic=create_claim
-// --execution=wasm
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --output=./pallets/poe/src/weights.rs
-// --template=./.maintain/frame-template-weight.hbs
-
+//! ESTIMATES ONLY: these numbers were written by hand from the storage accesses of every call
+//! and have not been produced by a benchmark run. Regenerate this file from the benchmarks in
+//! `benchmarking.rs` before relying on it for fees:
+//!
+//! ```text
+//! ./target/release/node-template benchmark --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet-poe --extrinsic=* --execution=wasm --wasm-execution=compiled \
+//!     --heap-pages=4096 --output=./pallets/poe/src/weights.rs \
+//!     --template=./.maintain/frame-template-weight.hbs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -45,24 +20,376 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_poe.
 pub trait WeightInfo {
-	fn create_claim(s: u32, ) -> Weight;
+	fn create_claim() -> Weight;
+	fn create_claim_with_content(c: u32, ) -> Weight;
+	fn on_initialize(e: u32, ) -> Weight;
+	fn revoke_claim(s: u32, ) -> Weight;
+	fn transfer_claim(s: u32, ) -> Weight;
+	fn offer_claim() -> Weight;
+	fn accept_claim() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn set_claim_metadata(m: u32, ) -> Weight;
+	fn set_co_owners(c: u32, ) -> Weight;
+	fn add_delegate() -> Weight;
+	fn remove_delegate() -> Weight;
+	fn create_claim_for() -> Weight;
+	fn create_claim_on_behalf() -> Weight;
+	fn freeze_claim() -> Weight;
+	fn unfreeze_claim() -> Weight;
+	fn force_revoke() -> Weight;
+	fn force_transfer() -> Weight;
+	fn anchor_merkle_root() -> Weight;
+	fn create_claims(n: u32, ) -> Weight;
+	fn revoke_claims(n: u32, ) -> Weight;
+	fn create_registry() -> Weight;
+	fn set_registry_policy() -> Weight;
+	fn add_registry_member() -> Weight;
+	fn remove_registry_member() -> Weight;
+	fn create_registry_claim() -> Weight;
+	fn revoke_registry_claim(s: u32, ) -> Weight;
+	fn transfer_registry_claim(s: u32, ) -> Weight;
+	fn destroy_registry() -> Weight;
+	fn clear_revocation() -> Weight;
+	fn cancel_action() -> Weight;
 }
 
-/// Weights for pallet_poe using the Substrate node and recommended hardware.
+/// Estimated weights for pallet_poe on the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn create_claim(_s: u32, ) -> Weight {
-		(16_087_000 as Weight)
+	fn create_claim() -> Weight {
+		(48_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn create_claim_with_content(c: u32, ) -> Weight {
+		(50_204_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn on_initialize(e: u32, ) -> Weight {
+		(3_112_000 as Weight)
+			.saturating_add((24_530_000 as Weight).saturating_mul(e as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+	}
+	fn revoke_claim(s: u32, ) -> Weight {
+		(41_208_000 as Weight)
+			.saturating_add((12_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	fn transfer_claim(s: u32, ) -> Weight {
+		(62_331_000 as Weight)
+			.saturating_add((15_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn offer_claim() -> Weight {
+		(24_106_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn accept_claim() -> Weight {
+		(64_870_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn cancel_offer() -> Weight {
+		(22_514_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_claim_metadata(m: u32, ) -> Weight {
+		(23_920_000 as Weight)
+			.saturating_add((9_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_co_owners(c: u32, ) -> Weight {
+		(25_437_000 as Weight)
+			.saturating_add((412_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn add_delegate() -> Weight {
+		(17_302_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_delegate() -> Weight {
+		(18_745_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn create_claim_for() -> Weight {
+		(51_094_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn create_claim_on_behalf() -> Weight {
+		(131_406_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn freeze_claim() -> Weight {
+		(15_683_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_claim() -> Weight {
+		(14_921_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn force_revoke() -> Weight {
+		(40_477_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	fn force_transfer() -> Weight {
+		(59_860_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn anchor_merkle_root() -> Weight {
+		(50_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn create_claims(n: u32, ) -> Weight {
+		(1_705_000 as Weight)
+			.saturating_add((38_910_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	fn revoke_claims(n: u32, ) -> Weight {
+		(2_154_000 as Weight)
+			.saturating_add((31_276_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+	fn create_registry() -> Weight {
+		(37_921_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn set_registry_policy() -> Weight {
+		(20_846_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn add_registry_member() -> Weight {
+		(22_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_registry_member() -> Weight {
+		(22_684_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn create_registry_claim() -> Weight {
+		(47_486_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn revoke_registry_claim(s: u32, ) -> Weight {
+		(44_107_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn transfer_registry_claim(s: u32, ) -> Weight {
+		(58_731_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn destroy_registry() -> Weight {
+		(31_562_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn clear_revocation() -> Weight {
+		(25_217_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_action() -> Weight {
+		(24_803_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn create_claim(_s: u32, ) -> Weight {
-		(16_087_000 as Weight)
+	fn create_claim() -> Weight {
+		(48_512_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn create_claim_with_content(c: u32, ) -> Weight {
+		(50_204_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn on_initialize(e: u32, ) -> Weight {
+		(3_112_000 as Weight)
+			.saturating_add((24_530_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+	}
+	fn revoke_claim(s: u32, ) -> Weight {
+		(41_208_000 as Weight)
+			.saturating_add((12_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	fn transfer_claim(s: u32, ) -> Weight {
+		(62_331_000 as Weight)
+			.saturating_add((15_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn offer_claim() -> Weight {
+		(24_106_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn accept_claim() -> Weight {
+		(64_870_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn cancel_offer() -> Weight {
+		(22_514_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_claim_metadata(m: u32, ) -> Weight {
+		(23_920_000 as Weight)
+			.saturating_add((9_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_co_owners(c: u32, ) -> Weight {
+		(25_437_000 as Weight)
+			.saturating_add((412_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn add_delegate() -> Weight {
+		(17_302_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_delegate() -> Weight {
+		(18_745_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn create_claim_for() -> Weight {
+		(51_094_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn create_claim_on_behalf() -> Weight {
+		(131_406_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn freeze_claim() -> Weight {
+		(15_683_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unfreeze_claim() -> Weight {
+		(14_921_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn force_revoke() -> Weight {
+		(40_477_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	fn force_transfer() -> Weight {
+		(59_860_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn anchor_merkle_root() -> Weight {
+		(50_118_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn create_claims(n: u32, ) -> Weight {
+		(1_705_000 as Weight)
+			.saturating_add((38_910_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	fn revoke_claims(n: u32, ) -> Weight {
+		(2_154_000 as Weight)
+			.saturating_add((31_276_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+	fn create_registry() -> Weight {
+		(37_921_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_registry_policy() -> Weight {
+		(20_846_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-}
\ No newline at end of file
+	fn add_registry_member() -> Weight {
+		(22_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_registry_member() -> Weight {
+		(22_684_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn create_registry_claim() -> Weight {
+		(47_486_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn revoke_registry_claim(s: u32, ) -> Weight {
+		(44_107_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn transfer_registry_claim(s: u32, ) -> Weight {
+		(58_731_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn destroy_registry() -> Weight {
+		(31_562_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_revocation() -> Weight {
+		(25_217_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_action() -> Weight {
+		(24_803_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
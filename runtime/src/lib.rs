@@ -36,6 +36,7 @@ pub use frame_support::{
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::CurrencyAdapter;
+use frame_system::EnsureRoot;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
@@ -111,7 +112,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -280,19 +281,70 @@ impl pallet_sudo::Config for Runtime {
 
 // confure max and min length for claim
 parameter_types! {
-	pub const ProofMaxLength: usize = 32;
-	pub const ProofMinLength: usize = 4;
+	pub const ProofMaxLength: u32 = 32;
+	pub const ProofMinLength: u32 = 4;
+	pub const MaxMetadataLength: u32 = 256;
+	pub const ClaimDepositBase: Balance = 10;
+	pub const ClaimDepositPerByte: Balance = 1;
+	pub const MaxExpiriesPerBlock: u32 = 100;
+	pub const MaxClaimsPerAccount: u32 = 1_000;
+	pub const MaxBatchSize: u32 = 100;
+	pub const OfferDuration: BlockNumber = DAYS;
+	pub const MaxHistoryLength: u32 = 100;
+	pub const MaxCoOwners: u32 = 16;
+	pub const ApprovalPeriod: BlockNumber = 7 * DAYS;
+	pub const ReclaimCooldown: BlockNumber = DAYS;
+	pub const RevocationDeposit: Balance = 10;
+	pub const MaxContentLength: u32 = 1024 * 1024;
+	pub const RegistryDeposit: Balance = 1_000;
 
     // for kitties pallet
     pub const BalanceToReserve: Balance = 20;
     pub const KittiesPalletId: PalletId = PalletId(*b"py/kitty");
 }
 
+/// Signs the benchmark payloads of pallet-poe with a new sr25519 key of the benchmark keystore.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SignWithNewKey;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<Signature, AccountId> for SignWithNewKey {
+	fn sign(payload: &[u8]) -> (AccountId, Signature) {
+		let key_type = sp_core::crypto::key_types::ACCOUNT;
+		let public = sp_io::crypto::sr25519_generate(key_type, None);
+		let signature = sp_io::crypto::sr25519_sign(key_type, &public, payload)
+			.expect("the key was just generated in the keystore; qed");
+		(sp_runtime::MultiSigner::from(public).into_account(), signature.into())
+	}
+}
+
 /// Configure the pallet-poe in pallets/poe.
 impl pallet_poe::Config for Runtime {
 	type Event = Event;
 	type ProofMaxLength = ProofMaxLength;
 	type ProofMinLength = ProofMinLength;
+	type MaxMetadataLength = MaxMetadataLength;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type MaxBatchSize = MaxBatchSize;
+	type OfferDuration = OfferDuration;
+	type MaxHistoryLength = MaxHistoryLength;
+	type MaxCoOwners = MaxCoOwners;
+	type ApprovalPeriod = ApprovalPeriod;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SignWithNewKey;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type UnixTime = Timestamp;
+	type ReclaimCooldown = ReclaimCooldown;
+	type RevocationDeposit = RevocationDeposit;
+	type MaxContentLength = MaxContentLength;
+	type OnClaimChange = ();
+	type RegistryDeposit = RegistryDeposit;
 	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 }
 
@@ -304,6 +356,7 @@ impl pallet_kitties::Config for Runtime {
     type KittyIndex = KittyIndex;
     type BalanceToReserve = BalanceToReserve;
     type PalletId = KittiesPalletId;
+    type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
 }
 
 /// For pallet-ocw
@@ -379,7 +432,7 @@ construct_runtime!(
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
 		// Include the custom logic from the pallet-poe in the runtime.
-		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>},
+		PoeModule: pallet_poe::{Pallet, Call, Config<T>, Storage, Event<T>},
         KittiesModule: pallet_kitties::{Pallet, Call, Storage, Event<T>},
 		OcwDemo: pallet_ocw::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
 	}
@@ -541,6 +594,37 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_rpc_runtime_api::PoeApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn claim(
+			proof: Vec<u8>,
+		) -> Option<pallet_poe::ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>> {
+			PoeModule::claim(proof)
+		}
+
+		fn registry_claim(
+			registry_id: pallet_poe::RegistryId,
+			proof: Vec<u8>,
+		) -> Option<pallet_poe::ClaimInfo<AccountId, BlockNumber, Balance, Vec<u8>>> {
+			PoeModule::registry_claim(registry_id, proof)
+		}
+
+		fn claims_of(account: AccountId, start: u32, limit: u32) -> Vec<Vec<u8>> {
+			PoeModule::claims_of(&account, start, limit)
+		}
+
+		fn is_claimed(proof: Vec<u8>) -> bool {
+			PoeModule::is_claimed(&proof)
+		}
+
+		fn claim_status(proof: Vec<u8>) -> pallet_poe::ClaimStatus<AccountId, BlockNumber, Balance> {
+			PoeModule::claim_status(&proof)
+		}
+
+		fn verify_inclusion(root: [u8; 32], leaf: [u8; 32], proof_path: Vec<[u8; 32]>) -> bool {
+			PoeModule::verify_inclusion(root, leaf, proof_path)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn dispatch_benchmark(
@@ -577,6 +661,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, pallet_poe, PoeModule);
+			add_benchmark!(params, batches, pallet_kitties, KittiesModule);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok((batches, storage_info))